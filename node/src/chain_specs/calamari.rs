@@ -18,6 +18,8 @@ use super::*;
 use crate::command::CALAMARI_PARACHAIN_ID;
 
 use calamari_runtime::{CouncilConfig, DemocracyConfig, GenesisConfig, TechnicalCommitteeConfig};
+use sp_runtime::Percent;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 /// Specialized `ChainSpec` for the normal parachain runtime.
 pub type CalamariChainSpec =
@@ -147,11 +149,25 @@ pub fn calamari_local_config() -> CalamariChainSpec {
 	)
 }
 
+/// The default vesting schedule of the development chains: 45% unlocks 10 minutes from now,
+/// then 11% every hour.
+fn dev_vesting_schedule() -> Vec<(Percent, u64)> {
+	let now = SystemTime::now()
+		.duration_since(UNIX_EPOCH)
+		.expect("the system clock is set before the unix epoch")
+		.as_secs();
+	let first = now + 10 * 60;
+	let mut schedule = vec![(Percent::from_percent(45), first)];
+	schedule.extend((1..=5).map(|i| (Percent::from_percent(11), first + i * 60 * 60)));
+	schedule
+}
+
 fn calamari_dev_genesis(
 	invulnerables: Vec<(AccountId, AuraId)>,
 	root_key: AccountId,
 	endowed_accounts: Vec<AccountId>,
 ) -> calamari_runtime::GenesisConfig {
+	let endowment = 100 * CALAMARI_ENDOWMENT / ((endowed_accounts.len() / 2) as Balance);
 	calamari_runtime::GenesisConfig {
 		system: calamari_runtime::SystemConfig {
 			code: calamari_runtime::WASM_BINARY
@@ -161,12 +177,7 @@ fn calamari_dev_genesis(
 		balances: calamari_runtime::BalancesConfig {
 			balances: endowed_accounts[..endowed_accounts.len() / 2]
 				.iter()
-				.map(|k| {
-					(
-						k.clone(),
-						100 * CALAMARI_ENDOWMENT / ((endowed_accounts.len() / 2) as Balance),
-					)
-				})
+				.map(|k| (k.clone(), endowment))
 				.collect(),
 		},
 		// no need to pass anything to aura, in fact it will panic if we do. Session will take care
@@ -212,6 +223,20 @@ fn calamari_dev_genesis(
 		polkadot_xcm: calamari_runtime::PolkadotXcmConfig {
 			safe_xcm_version: Some(SAFE_XCM_VERSION),
 		},
+		// vest a tenth of the endowment of Alice and Bob, on a schedule which starts unlocking
+		// shortly after the chain is launched
+		calamari_vesting: calamari_runtime::CalamariVestingConfig {
+			vesting_schedule: dev_vesting_schedule(),
+			vesting: ["Alice", "Bob"]
+				.iter()
+				.map(|seed| {
+					(
+						get_account_id_from_seed::<sr25519::Public>(seed),
+						endowment / 10,
+					)
+				})
+				.collect(),
+		},
 	}
 }
 
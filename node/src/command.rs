@@ -437,7 +437,8 @@ pub fn run() -> Result<()> {
 						manta_runtime::RuntimeApi,
 						MantaRuntimeExecutor,
 						manta_primitives::AuraId,
-					>(config, polkadot_config, id)
+						_,
+					>(config, polkadot_config, id, |_, _| {})
 					.await
 					.map(|r| r.0)
 					.map_err(Into::into)
@@ -446,7 +447,8 @@ pub fn run() -> Result<()> {
 						calamari_runtime::RuntimeApi,
 						CalamariRuntimeExecutor,
 						manta_primitives::AuraId,
-					>(config, polkadot_config, id)
+						_,
+					>(config, polkadot_config, id, crate::rpc::extend_calamari)
 					.await
 					.map(|r| r.0)
 					.map_err(Into::into)
@@ -455,7 +457,8 @@ pub fn run() -> Result<()> {
 						dolphin_runtime::RuntimeApi,
 						DolphinRuntimeExecutor,
 						manta_primitives::AuraId,
-					>(config, polkadot_config, id)
+						_,
+					>(config, polkadot_config, id, |_, _| {})
 					.await
 					.map(|r| r.0)
 					.map_err(Into::into)
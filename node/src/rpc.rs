@@ -52,11 +52,13 @@ where
 		+ 'static,
 	C::Api: frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
 	C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
+	C::Api: pallet_tx_pause_rpc::TransactionPauseRuntimeApi<Block>,
 	C::Api: BlockBuilder<Block>,
 	P: TransactionPool + Sync + Send + 'static,
 {
 	use frame_rpc_system::{FullSystem, SystemApi};
 	use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApi};
+	use pallet_tx_pause_rpc::{TransactionPause, TransactionPauseApi};
 
 	let mut io = jsonrpc_core::IoHandler::default();
 	let FullDeps {
@@ -71,8 +73,22 @@ where
 		deny_unsafe,
 	)));
 	io.extend_with(TransactionPaymentApi::to_delegate(TransactionPayment::new(
+		client.clone(),
+	)));
+	io.extend_with(TransactionPauseApi::to_delegate(TransactionPause::new(
 		client,
 	)));
 
 	io
 }
+
+/// Instantiate the RPC extensions only served by Calamari.
+pub fn extend_calamari<C>(client: Arc<C>, io: &mut RpcExtension)
+where
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: calamari_vesting_rpc::VestingRuntimeApi<Block, AccountId, Balance>,
+{
+	use calamari_vesting_rpc::{Vesting, VestingApi};
+
+	io.extend_with(VestingApi::to_delegate(Vesting::new(client)));
+}
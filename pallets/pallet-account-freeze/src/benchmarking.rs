@@ -0,0 +1,59 @@
+// Copyright 2020-2022 Manta Network.
+// This file is part of Manta.
+//
+// Manta is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Manta is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Manta.  If not, see <http://www.gnu.org/licenses/>.
+
+//! AccountFreeze pallet benchmarking.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite};
+use frame_system::{EventRecord, RawOrigin};
+
+use crate::Pallet as AccountFreeze;
+
+pub fn assert_last_event<T: Config>(generic_event: <T as Config>::Event) {
+	let events = frame_system::Pallet::<T>::events();
+	let system_event: <T as frame_system::Config>::Event = generic_event.into();
+	let EventRecord { event, .. } = &events[events.len() - 1];
+	assert_eq!(event, &system_event);
+}
+
+benchmarks! {
+
+	// Benchmark `freeze_account` extrinsic:
+	freeze_account {
+		let account: T::AccountId = account("frozen", 0, 0);
+	}: freeze_account(RawOrigin::Root, account.clone())
+	verify {
+		assert_last_event::<T>(Event::AccountFrozen(account).into());
+	}
+
+	// Benchmark `unfreeze_account` extrinsic:
+	unfreeze_account {
+		let account: T::AccountId = account("frozen", 0, 0);
+		AccountFreeze::<T>::freeze_account(RawOrigin::Root.into(), account.clone())?;
+	}: unfreeze_account(RawOrigin::Root, account.clone())
+	verify {
+		assert_last_event::<T>(Event::AccountUnfrozen(account).into());
+	}
+}
+
+impl_benchmark_test_suite!(
+	AccountFreeze,
+	crate::mock::ExtBuilder::default().build(),
+	crate::mock::Runtime,
+);
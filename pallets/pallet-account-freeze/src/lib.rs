@@ -0,0 +1,330 @@
+// Copyright 2020-2022 Manta Network.
+// This file is part of Manta.
+//
+// Manta is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Manta is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Manta.  If not, see <http://www.gnu.org/licenses/>.
+
+//! # Account Freeze Pallet
+//!
+//! Freezes compromised accounts, so that no signed transaction from them is accepted while
+//! governance responds, except the calls in `RecoveryCalls`.
+//!
+//! The runtime `BaseCallFilter` only sees the call, so the freeze is enforced by the
+//! `CheckAccountFreeze` signed extension instead. Calls which dispatch as another account,
+//! e.g. through `pallet_multisig` or `pallet_utility::as_derivative`, are refused as well when
+//! that account is frozen, see `DispatchAccounts`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+use frame_support::{
+	pallet_prelude::*,
+	traits::{Contains, IsSubType},
+};
+use frame_system::pallet_prelude::*;
+use sp_runtime::{
+	traits::{DispatchInfoOf, SignedExtension},
+	transaction_validity::{
+		InvalidTransaction, TransactionValidity, TransactionValidityError, ValidTransaction,
+	},
+};
+use sp_std::{prelude::*, vec::Vec};
+
+mod mock;
+mod tests;
+pub mod weights;
+
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+/// The runtime `Call`.
+pub type CallOf<T> = <T as frame_system::Config>::Call;
+
+/// The `InvalidTransaction::Custom` code of a transaction refused by `CheckAccountFreeze`.
+pub const ACCOUNT_FROZEN: u8 = 1;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The origin which may freeze and unfreeze accounts.
+		type FreezeOrigin: EnsureOrigin<Self::Origin>;
+
+		/// The calls which a frozen account may still sign, to recover from the incident.
+		type RecoveryCalls: Contains<<Self as frame_system::Config>::Call>;
+
+		/// The other accounts a signed call dispatches as, which must not be frozen either.
+		type DispatchAccounts: DispatchAccounts<
+			Self::AccountId,
+			<Self as frame_system::Config>::Call,
+		>;
+
+		/// Weight information for the extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// account is already frozen
+		AlreadyFrozen,
+		/// account is not frozen
+		NotFrozen,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Froze an account . \[account\]
+		AccountFrozen(T::AccountId),
+		/// Unfroze an account . \[account\]
+		AccountUnfrozen(T::AccountId),
+	}
+
+	/// The frozen accounts
+	///
+	/// map AccountId => Option<BlockNumber frozen at>
+	#[pallet::storage]
+	#[pallet::getter(fn frozen_accounts)]
+	pub type FrozenAccounts<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, T::BlockNumber, OptionQuery>;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Freeze an account, so that `CheckAccountFreeze` refuses its signed transactions
+		/// except `RecoveryCalls`.
+		#[pallet::weight(T::WeightInfo::freeze_account())]
+		pub fn freeze_account(origin: OriginFor<T>, account: T::AccountId) -> DispatchResult {
+			T::FreezeOrigin::ensure_origin(origin)?;
+			ensure!(
+				!FrozenAccounts::<T>::contains_key(&account),
+				Error::<T>::AlreadyFrozen
+			);
+
+			FrozenAccounts::<T>::insert(&account, frame_system::Pallet::<T>::block_number());
+			Self::deposit_event(Event::AccountFrozen(account));
+			Ok(())
+		}
+
+		/// Unfreeze an account frozen by `freeze_account`.
+		#[pallet::weight(T::WeightInfo::unfreeze_account())]
+		pub fn unfreeze_account(origin: OriginFor<T>, account: T::AccountId) -> DispatchResult {
+			T::FreezeOrigin::ensure_origin(origin)?;
+			ensure!(
+				FrozenAccounts::<T>::take(&account).is_some(),
+				Error::<T>::NotFrozen
+			);
+
+			Self::deposit_event(Event::AccountUnfrozen(account));
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Whether `account` is frozen.
+	pub fn is_frozen(account: &T::AccountId) -> bool {
+		FrozenAccounts::<T>::contains_key(account)
+	}
+
+	/// Whether `who` may sign `call`, i.e. neither `who` nor any account `call` dispatches as
+	/// is frozen, unless `call` is one of the `RecoveryCalls` of `who`.
+	pub fn can_sign(who: &T::AccountId, call: &CallOf<T>) -> bool {
+		if Self::is_frozen(who) && !T::RecoveryCalls::contains(call) {
+			return false;
+		}
+		!T::DispatchAccounts::dispatch_accounts(who, call)
+			.iter()
+			.any(Self::is_frozen)
+	}
+}
+
+/// The accounts which a call signed by `who` dispatches calls as, other than `who` itself.
+pub trait DispatchAccounts<AccountId, Call> {
+	/// The accounts `call` signed by `who` dispatches as, e.g. the multisig account of
+	/// `pallet_multisig::as_multi`.
+	fn dispatch_accounts(who: &AccountId, call: &Call) -> Vec<AccountId>;
+}
+
+impl<AccountId, Call> DispatchAccounts<AccountId, Call> for () {
+	fn dispatch_accounts(_who: &AccountId, _call: &Call) -> Vec<AccountId> {
+		Vec::new()
+	}
+}
+
+/// `DispatchAccounts` of `pallet_multisig` and `pallet_utility`.
+///
+/// These are the multisig account of `as_multi` and `as_multi_threshold_1`, and the derivative
+/// account of `as_derivative`, looking into the calls of `batch` and `batch_all` and into the
+/// calls dispatched by `as_multi`, `as_multi_threshold_1` and `as_derivative`. The call of
+/// `as_multi` is kept encoded and is only looked into if it decodes, its multisig account is
+/// checked by every signatory anyway.
+pub struct MultisigAndUtilityAccounts<T>(PhantomData<T>);
+impl<T> DispatchAccounts<T::AccountId, CallOf<T>> for MultisigAndUtilityAccounts<T>
+where
+	T: pallet_multisig::Config<Call = CallOf<T>> + pallet_utility::Config<Call = CallOf<T>>,
+	CallOf<T>: IsSubType<pallet_multisig::Call<T>> + IsSubType<pallet_utility::Call<T>>,
+{
+	fn dispatch_accounts(who: &T::AccountId, call: &CallOf<T>) -> Vec<T::AccountId> {
+		let mut accounts = Vec::new();
+		Self::collect(who, call, &mut accounts);
+		accounts
+	}
+}
+
+impl<T> MultisigAndUtilityAccounts<T>
+where
+	T: pallet_multisig::Config<Call = CallOf<T>> + pallet_utility::Config<Call = CallOf<T>>,
+	CallOf<T>: IsSubType<pallet_multisig::Call<T>> + IsSubType<pallet_utility::Call<T>>,
+{
+	fn collect(who: &T::AccountId, call: &CallOf<T>, accounts: &mut Vec<T::AccountId>) {
+		if let Some(multisig_call) =
+			<CallOf<T> as IsSubType<pallet_multisig::Call<T>>>::is_sub_type(call)
+		{
+			match multisig_call {
+				pallet_multisig::Call::as_multi_threshold_1 {
+					other_signatories,
+					call,
+				} => {
+					let multisig = pallet_multisig::Pallet::<T>::multi_account_id(
+						&Self::signatories(other_signatories, who),
+						1,
+					);
+					Self::collect(&multisig, call, accounts);
+					accounts.push(multisig);
+				}
+				pallet_multisig::Call::as_multi {
+					threshold,
+					other_signatories,
+					call,
+					..
+				} => {
+					let multisig = pallet_multisig::Pallet::<T>::multi_account_id(
+						&Self::signatories(other_signatories, who),
+						*threshold,
+					);
+					if let Some(call) = call.try_decode() {
+						Self::collect(&multisig, &call, accounts);
+					}
+					accounts.push(multisig);
+				}
+				_ => {}
+			}
+		} else if let Some(utility_call) =
+			<CallOf<T> as IsSubType<pallet_utility::Call<T>>>::is_sub_type(call)
+		{
+			match utility_call {
+				pallet_utility::Call::as_derivative { index, call } => {
+					let derivative =
+						pallet_utility::Pallet::<T>::derivative_account_id(who.clone(), *index);
+					Self::collect(&derivative, call, accounts);
+					accounts.push(derivative);
+				}
+				pallet_utility::Call::batch { calls }
+				| pallet_utility::Call::batch_all { calls } => {
+					for call in calls {
+						Self::collect(who, call, accounts);
+					}
+				}
+				_ => {}
+			}
+		}
+	}
+
+	/// `other_signatories` with `who` inserted in order, as `pallet_multisig` does.
+	fn signatories(other_signatories: &[T::AccountId], who: &T::AccountId) -> Vec<T::AccountId> {
+		let mut signatories = other_signatories.to_vec();
+		let index = signatories.binary_search(who).unwrap_or_else(|index| index);
+		signatories.insert(index, who.clone());
+		signatories
+	}
+}
+
+/// Refuses the signed transactions of frozen accounts at transaction validation,
+/// except `RecoveryCalls`, and those dispatching as a frozen account.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct CheckAccountFreeze<T: Config + Send + Sync>(sp_std::marker::PhantomData<T>);
+
+impl<T: Config + Send + Sync> CheckAccountFreeze<T> {
+	/// Create new `SignedExtension` to check for frozen accounts.
+	pub fn new() -> Self {
+		Self(sp_std::marker::PhantomData)
+	}
+}
+
+impl<T: Config + Send + Sync> Default for CheckAccountFreeze<T> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<T: Config + Send + Sync> sp_std::fmt::Debug for CheckAccountFreeze<T> {
+	#[cfg(feature = "std")]
+	fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		write!(f, "CheckAccountFreeze")
+	}
+
+	#[cfg(not(feature = "std"))]
+	fn fmt(&self, _: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		Ok(())
+	}
+}
+
+impl<T: Config + Send + Sync> SignedExtension for CheckAccountFreeze<T> {
+	const IDENTIFIER: &'static str = "CheckAccountFreeze";
+	type AccountId = T::AccountId;
+	type Call = CallOf<T>;
+	type AdditionalSigned = ();
+	type Pre = ();
+
+	fn additional_signed(&self) -> Result<(), TransactionValidityError> {
+		Ok(())
+	}
+
+	fn validate(
+		&self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		_info: &DispatchInfoOf<Self::Call>,
+		_len: usize,
+	) -> TransactionValidity {
+		if Pallet::<T>::can_sign(who, call) {
+			Ok(ValidTransaction::default())
+		} else {
+			Err(InvalidTransaction::Custom(ACCOUNT_FROZEN).into())
+		}
+	}
+
+	fn pre_dispatch(
+		self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		len: usize,
+	) -> Result<(), TransactionValidityError> {
+		self.validate(who, call, info, len).map(|_| ())
+	}
+}
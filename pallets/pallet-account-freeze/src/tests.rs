@@ -0,0 +1,212 @@
+// Copyright 2020-2022 Manta Network.
+// This file is part of Manta.
+//
+// Manta is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Manta is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Manta.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Unit tests for the account freeze pallet.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{assert_noop, assert_ok, weights::DispatchInfo};
+use frame_system::RawOrigin;
+use mock::{Event, *};
+use sp_runtime::traits::BadOrigin;
+
+fn transfer() -> Call {
+	Call::Balances(pallet_balances::Call::transfer {
+		dest: CHARLIE,
+		value: 10,
+	})
+}
+
+fn validate(who: AccountId, call: &Call) -> TransactionValidity {
+	CheckAccountFreeze::<Runtime>::new().validate(&who, call, &DispatchInfo::default(), 0)
+}
+
+fn frozen() -> TransactionValidity {
+	Err(InvalidTransaction::Custom(ACCOUNT_FROZEN).into())
+}
+
+#[test]
+fn freeze_and_unfreeze_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			AccountFreeze::freeze_account(Origin::signed(1), ALICE),
+			BadOrigin
+		);
+		assert_ok!(AccountFreeze::freeze_account(Origin::signed(2), ALICE));
+		System::assert_last_event(Event::AccountFreeze(crate::Event::AccountFrozen(ALICE)));
+		assert_eq!(AccountFreeze::frozen_accounts(ALICE), Some(1));
+		assert!(AccountFreeze::is_frozen(&ALICE));
+		assert_noop!(
+			AccountFreeze::freeze_account(RawOrigin::Root.into(), ALICE),
+			Error::<Runtime>::AlreadyFrozen
+		);
+
+		assert_noop!(
+			AccountFreeze::unfreeze_account(Origin::signed(1), ALICE),
+			BadOrigin
+		);
+		assert_ok!(AccountFreeze::unfreeze_account(
+			RawOrigin::Root.into(),
+			ALICE
+		));
+		System::assert_last_event(Event::AccountFreeze(crate::Event::AccountUnfrozen(ALICE)));
+		assert!(!AccountFreeze::is_frozen(&ALICE));
+		assert_noop!(
+			AccountFreeze::unfreeze_account(RawOrigin::Root.into(), ALICE),
+			Error::<Runtime>::NotFrozen
+		);
+	});
+}
+
+#[test]
+fn frozen_account_cannot_sign() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(validate(ALICE, &transfer()));
+		assert_ok!(AccountFreeze::freeze_account(RawOrigin::Root.into(), ALICE));
+
+		assert_eq!(validate(ALICE, &transfer()), frozen());
+		assert_eq!(
+			CheckAccountFreeze::<Runtime>::new().pre_dispatch(
+				&ALICE,
+				&transfer(),
+				&DispatchInfo::default(),
+				0
+			),
+			Err(InvalidTransaction::Custom(ACCOUNT_FROZEN).into())
+		);
+		// recovery calls are still allowed
+		assert_ok!(validate(
+			ALICE,
+			&Call::System(frame_system::Call::remark { remark: vec![] })
+		));
+		// other accounts are not affected
+		assert_ok!(validate(BOB, &transfer()));
+
+		assert_ok!(AccountFreeze::unfreeze_account(
+			RawOrigin::Root.into(),
+			ALICE
+		));
+		assert_ok!(validate(ALICE, &transfer()));
+	});
+}
+
+#[test]
+fn calls_dispatching_as_frozen_multisig_are_refused() {
+	ExtBuilder::default().build().execute_with(|| {
+		let multisig = Multisig::multi_account_id(&[ALICE, BOB], 1);
+		let as_multi_threshold_1 = Call::Multisig(pallet_multisig::Call::as_multi_threshold_1 {
+			other_signatories: vec![ALICE],
+			call: Box::new(transfer()),
+		});
+		assert_ok!(validate(BOB, &as_multi_threshold_1));
+
+		assert_ok!(AccountFreeze::freeze_account(
+			RawOrigin::Root.into(),
+			multisig
+		));
+		assert_eq!(validate(BOB, &as_multi_threshold_1), frozen());
+		// the signatories themselves are not frozen
+		assert_ok!(validate(BOB, &transfer()));
+
+		let multisig = Multisig::multi_account_id(&[ALICE, BOB, CHARLIE], 2);
+		let as_multi = Call::Multisig(pallet_multisig::Call::as_multi {
+			threshold: 2,
+			other_signatories: vec![ALICE, CHARLIE],
+			maybe_timepoint: None,
+			call: pallet_multisig::OpaqueCall::<Runtime>::from_encoded(transfer().encode()),
+			store_call: false,
+			max_weight: 0,
+		});
+		assert_ok!(validate(BOB, &as_multi));
+		assert_ok!(AccountFreeze::freeze_account(
+			RawOrigin::Root.into(),
+			multisig
+		));
+		assert_eq!(validate(BOB, &as_multi), frozen());
+	});
+}
+
+#[test]
+fn calls_dispatching_as_frozen_derivative_are_refused() {
+	ExtBuilder::default().build().execute_with(|| {
+		let derivative = Utility::derivative_account_id(ALICE, 0);
+		let as_derivative = Call::Utility(pallet_utility::Call::as_derivative {
+			index: 0,
+			call: Box::new(transfer()),
+		});
+		let batch = Call::Utility(pallet_utility::Call::batch_all {
+			calls: vec![transfer(), as_derivative.clone()],
+		});
+		assert_ok!(validate(ALICE, &as_derivative));
+		assert_ok!(validate(ALICE, &batch));
+
+		assert_ok!(AccountFreeze::freeze_account(
+			RawOrigin::Root.into(),
+			derivative
+		));
+		assert_eq!(validate(ALICE, &as_derivative), frozen());
+		assert_eq!(validate(ALICE, &batch), frozen());
+		// the derivative of another account is not frozen
+		assert_ok!(validate(BOB, &as_derivative));
+
+		// a derivative of a multisig account
+		let multisig = Multisig::multi_account_id(&[ALICE, BOB], 1);
+		assert_ok!(AccountFreeze::freeze_account(
+			RawOrigin::Root.into(),
+			Utility::derivative_account_id(multisig, 0)
+		));
+		assert_eq!(
+			validate(
+				BOB,
+				&Call::Multisig(pallet_multisig::Call::as_multi_threshold_1 {
+					other_signatories: vec![ALICE],
+					call: Box::new(as_derivative.clone()),
+				})
+			),
+			frozen()
+		);
+
+		// the encoded call of `as_multi` is looked into too
+		let multisig = Multisig::multi_account_id(&[ALICE, BOB, CHARLIE], 2);
+		let as_multi = Call::Multisig(pallet_multisig::Call::as_multi {
+			threshold: 2,
+			other_signatories: vec![ALICE, CHARLIE],
+			maybe_timepoint: None,
+			call: pallet_multisig::OpaqueCall::<Runtime>::from_encoded(as_derivative.encode()),
+			store_call: false,
+			max_weight: 0,
+		});
+		assert_ok!(validate(BOB, &as_multi));
+		assert_ok!(AccountFreeze::freeze_account(
+			RawOrigin::Root.into(),
+			Utility::derivative_account_id(multisig, 0)
+		));
+		assert_eq!(validate(BOB, &as_multi), frozen());
+		// the multisig account itself is not frozen
+		assert!(!AccountFreeze::is_frozen(&multisig));
+		// a call which does not decode is not looked into
+		let undecodable = Call::Multisig(pallet_multisig::Call::as_multi {
+			threshold: 2,
+			other_signatories: vec![ALICE, CHARLIE],
+			maybe_timepoint: None,
+			call: pallet_multisig::OpaqueCall::<Runtime>::from_encoded(vec![0xff, 0xff]),
+			store_call: false,
+			max_weight: 0,
+		});
+		assert_ok!(validate(BOB, &undecodable));
+	});
+}
@@ -0,0 +1,82 @@
+// Copyright 2020-2022 Manta Network.
+// This file is part of Manta.
+
+// Manta is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Manta is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Manta.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Placeholder weights for pallet_account_freeze
+//!
+//! THESE ARE NOT BENCHMARK RESULTS. They were estimated by hand from the storage accesses
+//! of each extrinsic, and must be regenerated on reference hardware with the command below
+//! before they are relied on.
+
+// Command to regenerate:
+// manta
+// benchmark
+// --chain=manta-local
+// --pallet=pallet_account_freeze
+// --extrinsic=*
+// --execution=Wasm
+// --wasm-execution=Compiled
+// --heap-pages=4096
+// --repeat=20
+// --steps=50
+// --template=.github/resources/frame-weight-template.hbs
+// --output=pallet_account_freeze.rs
+
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_account_freeze.
+pub trait WeightInfo {
+	fn freeze_account() -> Weight;
+	fn unfreeze_account() -> Weight;
+}
+
+/// Weights for pallet_account_freeze using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: AccountFreeze FrozenAccounts (r:1 w:1)
+	fn freeze_account() -> Weight {
+		(54_194_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: AccountFreeze FrozenAccounts (r:1 w:1)
+	fn unfreeze_account() -> Weight {
+		(55_542_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: AccountFreeze FrozenAccounts (r:1 w:1)
+	fn freeze_account() -> Weight {
+		(54_194_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: AccountFreeze FrozenAccounts (r:1 w:1)
+	fn unfreeze_account() -> Weight {
+		(55_542_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+}
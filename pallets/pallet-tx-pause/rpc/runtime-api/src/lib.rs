@@ -0,0 +1,33 @@
+// Copyright 2020-2022 Manta Network.
+// This file is part of Manta.
+//
+// Manta is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Manta is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Manta.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Runtime API definition for the transaction pause pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet_tx_pause::{CallFilterStatus, PauseStatus};
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	pub trait TransactionPauseApi {
+		/// All the paused pallets, paused extrinsics and throttled extrinsics as
+		/// `(pallet_name, function_name, status)`, the function name being empty for a pallet.
+		fn paused_transactions() -> Vec<(Vec<u8>, Vec<u8>, PauseStatus)>;
+
+		/// Whether the runtime `BaseFilter` lets the SCALE encoded `call` through, and if not, why.
+		fn call_filter_status(call: Vec<u8>) -> CallFilterStatus;
+	}
+}
@@ -0,0 +1,129 @@
+// Copyright 2020-2022 Manta Network.
+// This file is part of Manta.
+//
+// Manta is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Manta is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Manta.  If not, see <http://www.gnu.org/licenses/>.
+
+//! RPC interface for the transaction pause pallet.
+
+use std::sync::Arc;
+
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+
+pub use pallet_tx_pause_rpc_runtime_api::{
+	CallFilterStatus, PauseStatus, TransactionPauseApi as TransactionPauseRuntimeApi,
+};
+
+/// Transaction pause RPC methods.
+#[rpc]
+pub trait TransactionPauseApi<BlockHash> {
+	/// All the paused pallets, paused extrinsics and throttled extrinsics as
+	/// `(pallet_name, function_name, status)`, the function name being empty for a pallet.
+	#[rpc(name = "txPause_pausedTransactions")]
+	fn paused_transactions(
+		&self,
+		at: Option<BlockHash>,
+	) -> Result<Vec<(String, String, PauseStatus)>>;
+
+	/// Whether the runtime `BaseFilter` lets the SCALE encoded call through, and if not, why.
+	#[rpc(name = "txPause_callFilterStatus")]
+	fn call_filter_status(
+		&self,
+		encoded_call: Bytes,
+		at: Option<BlockHash>,
+	) -> Result<CallFilterStatus>;
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+}
+
+impl From<Error> for i64 {
+	fn from(e: Error) -> i64 {
+		match e {
+			Error::RuntimeError => 1,
+		}
+	}
+}
+
+/// An implementation of transaction pause specific RPC methods.
+pub struct TransactionPause<C, B> {
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<B>,
+}
+
+impl<C, B> TransactionPause<C, B> {
+	/// Create new `TransactionPause` with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		Self {
+			client,
+			_marker: Default::default(),
+		}
+	}
+}
+
+fn runtime_error(message: &str, e: impl std::fmt::Debug) -> RpcError {
+	RpcError {
+		code: ErrorCode::ServerError(Error::RuntimeError.into()),
+		message: message.into(),
+		data: Some(format!("{:?}", e).into()),
+	}
+}
+
+impl<C, Block> TransactionPauseApi<<Block as BlockT>::Hash> for TransactionPause<C, Block>
+where
+	Block: BlockT,
+	C: 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api: TransactionPauseRuntimeApi<Block>,
+{
+	fn paused_transactions(
+		&self,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Vec<(String, String, PauseStatus)>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		let paused = api
+			.paused_transactions(&at)
+			.map_err(|e| runtime_error("Unable to query paused transactions.", e))?;
+		Ok(paused
+			.into_iter()
+			.map(|(pallet_name, function_name, status)| {
+				(
+					String::from_utf8_lossy(&pallet_name).into_owned(),
+					String::from_utf8_lossy(&function_name).into_owned(),
+					status,
+				)
+			})
+			.collect())
+	}
+
+	fn call_filter_status(
+		&self,
+		encoded_call: Bytes,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<CallFilterStatus> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.call_filter_status(&at, encoded_call.to_vec())
+			.map_err(|e| runtime_error("Unable to query call filter status.", e))
+	}
+}
@@ -28,6 +28,28 @@ use frame_system::{EventRecord, RawOrigin};
 
 use crate::Pallet as TransactionPause;
 
+/// Up to `n` pallet and extrinsic names of the runtime which can be paused.
+fn pausable_calls<T: Config>(n: u32) -> Vec<(Vec<u8>, Vec<u8>)> {
+	T::KnownCalls::get_module_names()
+		.iter()
+		.filter(|pallet_name| {
+			**pallet_name != <TransactionPause<T> as PalletInfoAccess>::name()
+				&& !CORE_PALLETS.contains(pallet_name)
+		})
+		.flat_map(|pallet_name| {
+			T::KnownCalls::get_call_names(pallet_name)
+				.iter()
+				.map(move |function_name| {
+					(
+						pallet_name.as_bytes().to_vec(),
+						function_name.as_bytes().to_vec(),
+					)
+				})
+		})
+		.take(n as usize)
+		.collect()
+}
+
 pub fn assert_last_event<T: Config>(generic_event: <T as Config>::Event) {
 	let events = frame_system::Pallet::<T>::events();
 	let system_event: <T as frame_system::Config>::Event = generic_event.into();
@@ -41,10 +63,18 @@ benchmarks! {
 	pause_transaction {
 		let pallet_name = b"Balances".to_vec();
 		let function_name =  b"transfer".to_vec();
-	}: pause_transaction(RawOrigin::Root, pallet_name.clone(), function_name.clone())
+		let expiry: T::BlockNumber = 10u32.into();
+		let reason = vec![0u8; T::MaxReasonLen::get() as usize];
+		let info = PauseInfo {
+			reason: reason.clone().try_into().unwrap(),
+			paused_at: frame_system::Pallet::<T>::block_number(),
+			paused_by: PausedBy::Root,
+			strict: false,
+		};
+	}: pause_transaction(RawOrigin::Root, pallet_name.clone(), function_name.clone(), Some(expiry), reason)
 	verify {
 		assert_last_event::<T>(
-			Event::TransactionPaused(pallet_name.clone(), function_name.clone()).into()
+			Event::TransactionPaused(pallet_name.clone(), function_name.clone(), Some(expiry), info).into()
 		);
 	}
 
@@ -54,12 +84,191 @@ benchmarks! {
 		let pallet_name = b"Balances".to_vec();
 		let function_name =  b"transfer".to_vec();
 
-		TransactionPause::<T>::pause_transaction(origin.clone(), pallet_name.clone(), function_name.clone())?;
+		TransactionPause::<T>::pause_transaction(origin.clone(), pallet_name.clone(), function_name.clone(), None, vec![])?;
 
 	}: unpause_transaction(RawOrigin::Root, pallet_name.clone(), function_name.clone())
 	verify {
 		assert_last_event::<T>(
-			Event::TransactionUnpaused(pallet_name.clone(), function_name.clone()).into()
+			Event::TransactionUnpaused(pallet_name.clone(), function_name.clone(), false).into()
+		);
+	}
+
+	// Benchmark `pause_pallet` extrinsic:
+	pause_pallet {
+		let pallet_name = b"Balances".to_vec();
+	}: pause_pallet(RawOrigin::Root, pallet_name.clone())
+	verify {
+		assert_last_event::<T>(
+			Event::PalletPaused(pallet_name.clone()).into()
+		);
+	}
+
+	// Benchmark `unpause_pallet` extrinsic:
+	unpause_pallet {
+		let origin: T::Origin = T::Origin::from(RawOrigin::Root);
+		let pallet_name = b"Balances".to_vec();
+
+		TransactionPause::<T>::pause_pallet(origin.clone(), pallet_name.clone())?;
+
+	}: unpause_pallet(RawOrigin::Root, pallet_name.clone())
+	verify {
+		assert_last_event::<T>(
+			Event::PalletUnpaused(pallet_name.clone()).into()
+		);
+	}
+
+	// Benchmark `on_initialize` lifting `n` expired pauses:
+	on_initialize {
+		let n in 0 .. 20;
+
+		let expiry: T::BlockNumber = 10u32.into();
+		// `on_initialize` does not look at the names, so they need not be real calls.
+		for i in 0 .. n {
+			let function_name = [b"call_".as_ref(), &i.to_le_bytes()].concat();
+			let key: (NameOf<T>, NameOf<T>) = (
+				b"Balances".to_vec().try_into().unwrap(),
+				function_name.try_into().unwrap(),
+			);
+			PausedTransactions::<T>::insert(&key, PauseInfo {
+				reason: Default::default(),
+				paused_at: frame_system::Pallet::<T>::block_number(),
+				paused_by: PausedBy::Root,
+				strict: false,
+			});
+			PauseExpiries::<T>::insert(&key, expiry);
+			PauseExpiryQueue::<T>::insert(expiry, &key, ());
+		}
+	}: {
+		TransactionPause::<T>::on_initialize(expiry);
+	}
+	verify {
+		assert!(PauseExpiryQueue::<T>::iter_prefix(expiry).next().is_none());
+	}
+
+	// Benchmark `pause_transactions` extrinsic with `n` calls:
+	pause_transactions {
+		let n in 1 .. T::MaxPauseBatch::get();
+
+		let calls = pausable_calls::<T>(n);
+		let reason = vec![0u8; T::MaxReasonLen::get() as usize];
+	}: pause_transactions(RawOrigin::Root, calls.clone(), reason)
+	verify {
+		assert_eq!(PausedTransactions::<T>::iter().count(), calls.len());
+	}
+
+	// Benchmark `unpause_transactions` extrinsic with `n` calls:
+	unpause_transactions {
+		let n in 1 .. T::MaxPauseBatch::get();
+
+		let origin: T::Origin = T::Origin::from(RawOrigin::Root);
+		let calls = pausable_calls::<T>(n);
+		TransactionPause::<T>::pause_transactions(origin, calls.clone(), vec![])?;
+	}: unpause_transactions(RawOrigin::Root, calls.clone())
+	verify {
+		assert_eq!(PausedTransactions::<T>::iter().count(), 0);
+	}
+
+	// Benchmark `halt` extrinsic:
+	halt {
+	}: halt(RawOrigin::Root)
+	verify {
+		assert_last_event::<T>(Event::EmergencyHalted.into());
+	}
+
+	// Benchmark `resume` extrinsic:
+	resume {
+		TransactionPause::<T>::halt(T::Origin::from(RawOrigin::Root))?;
+	}: resume(RawOrigin::Root)
+	verify {
+		assert_last_event::<T>(Event::EmergencyResumed.into());
+	}
+
+	// Benchmark `throttle_transaction` extrinsic:
+	throttle_transaction {
+		let pallet_name = b"Balances".to_vec();
+		let function_name = b"transfer".to_vec();
+	}: throttle_transaction(RawOrigin::Root, pallet_name.clone(), function_name.clone(), 10)
+	verify {
+		assert_last_event::<T>(
+			Event::TransactionThrottled(pallet_name.clone(), function_name.clone(), 10).into()
+		);
+	}
+
+	// Benchmark `unthrottle_transaction` extrinsic:
+	unthrottle_transaction {
+		let origin: T::Origin = T::Origin::from(RawOrigin::Root);
+		let pallet_name = b"Balances".to_vec();
+		let function_name = b"transfer".to_vec();
+
+		TransactionPause::<T>::throttle_transaction(origin, pallet_name.clone(), function_name.clone(), 10)?;
+	}: unthrottle_transaction(RawOrigin::Root, pallet_name.clone(), function_name.clone())
+	verify {
+		assert_last_event::<T>(
+			Event::TransactionUnthrottled(pallet_name.clone(), function_name.clone()).into()
+		);
+	}
+
+	// Benchmark `pause_call` extrinsic, without decoding and checking the call:
+	pause_call {
+		let info = PauseInfo {
+			reason: vec![0u8; T::MaxReasonLen::get() as usize].try_into().unwrap(),
+			paused_at: frame_system::Pallet::<T>::block_number(),
+			paused_by: PausedBy::Root,
+			strict: false,
+		};
+	}: {
+		TransactionPause::<T>::do_pause_call(10, 0, info.clone());
+	}
+	verify {
+		assert_last_event::<T>(Event::CallPaused(10, 0, info).into());
+	}
+
+	// Benchmark `unpause_call` extrinsic:
+	unpause_call {
+		let info = PauseInfo {
+			reason: Default::default(),
+			paused_at: frame_system::Pallet::<T>::block_number(),
+			paused_by: PausedBy::Root,
+			strict: false,
+		};
+		TransactionPause::<T>::do_pause_call(10, 0, info);
+	}: unpause_call(RawOrigin::Root, 10, 0)
+	verify {
+		assert_last_event::<T>(Event::CallUnpaused(10, 0).into());
+	}
+
+	// Benchmark `set_strict_pause` extrinsic:
+	set_strict_pause {
+		let origin: T::Origin = T::Origin::from(RawOrigin::Root);
+		let pallet_name = b"Balances".to_vec();
+		let function_name = b"transfer".to_vec();
+
+		TransactionPause::<T>::pause_transaction(origin, pallet_name.clone(), function_name.clone(), None, vec![])?;
+	}: set_strict_pause(RawOrigin::Root, pallet_name.clone(), function_name.clone(), true)
+	verify {
+		assert_last_event::<T>(
+			Event::StrictPauseSet(pallet_name.clone(), function_name.clone(), true).into()
+		);
+	}
+
+	// Benchmark `force_dispatch_paused` extrinsic, dispatching an empty remark
+	// whose strict pause is written to storage directly:
+	force_dispatch_paused {
+		let key = (
+			b"System".to_vec().try_into().unwrap(),
+			b"remark".to_vec().try_into().unwrap(),
+		);
+		PausedTransactions::<T>::insert(&key, PauseInfo {
+			reason: Default::default(),
+			paused_at: frame_system::Pallet::<T>::block_number(),
+			paused_by: PausedBy::Root,
+			strict: true,
+		});
+		let call: T::KnownCalls = frame_system::Call::<T>::remark { remark: vec![] }.into();
+	}: force_dispatch_paused(RawOrigin::Root, Box::new(call))
+	verify {
+		assert_last_event::<T>(
+			Event::PausedCallDispatched(b"System".to_vec(), b"remark".to_vec(), Ok(())).into()
 		);
 	}
 }
@@ -23,16 +23,33 @@
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+use codec::{EncodeLike, Input, Output};
 use frame_support::{
-	dispatch::{CallMetadata, GetCallMetadata},
+	dispatch::{
+		CallMetadata, DispatchInfo, Dispatchable, GetCallMetadata, GetCallName, GetDispatchInfo,
+		PostDispatchInfo,
+	},
 	pallet_prelude::*,
-	traits::{Contains, PalletInfoAccess},
+	storage::with_transaction,
+	traits::{
+		Contains, PalletInfoAccess, PalletsInfoAccess, StorageVersion, UnfilteredDispatchable,
+	},
 	transactional,
+	weights::extract_actual_weight,
 };
 use frame_system::pallet_prelude::*;
-use sp_runtime::DispatchResult;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_runtime::{
+	traits::{DispatchInfoOf, One, Saturating, SignedExtension},
+	transaction_validity::{
+		InvalidTransaction, TransactionValidity, TransactionValidityError, ValidTransaction,
+	},
+	DispatchResult, RuntimeDebug, TransactionOutcome,
+};
 use sp_std::{prelude::*, vec::Vec};
 
+pub mod migrations;
 mod mock;
 mod tests;
 pub mod weights;
@@ -40,16 +57,250 @@ pub mod weights;
 pub use pallet::*;
 pub use weights::WeightInfo;
 
+/// A pallet or function name, bounded by `MaxNameLen`.
+pub type NameOf<T> = BoundedVec<u8, <T as Config>::MaxNameLen>;
+
+/// The reason given for a pause, bounded by `MaxReasonLen`.
+pub type ReasonOf<T> = BoundedVec<u8, <T as Config>::MaxReasonLen>;
+
+/// The `PauseInfo` of a paused transaction in a runtime.
+pub type PauseInfoOf<T> = PauseInfo<
+	<T as frame_system::Config>::BlockNumber,
+	<T as frame_system::Config>::AccountId,
+	ReasonOf<T>,
+>;
+
+/// The origin which paused a transaction.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum PausedBy<AccountId> {
+	/// The root origin.
+	Root,
+	/// A signed origin.
+	Signed(AccountId),
+	/// Any other origin accepted by `PauseOrigin`, e.g. a collective.
+	Other,
+}
+
+/// Why, when and by whom a transaction was paused.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PauseInfo<BlockNumber, AccountId, Reason> {
+	/// The justification given for the pause.
+	pub reason: Reason,
+	/// The block in which the pause was made.
+	pub paused_at: BlockNumber,
+	/// The origin which made the pause.
+	pub paused_by: PausedBy<AccountId>,
+	/// Whether the pause also applies to calls dispatched by Root, see `set_strict_pause`.
+	pub strict: bool,
+}
+
+/// Whether the runtime `BaseFilter` lets a call through, and if not, why.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub enum CallFilterStatus {
+	/// The call is allowed.
+	Allowed,
+	/// All calls but the allow-listed ones are halted by `halt`.
+	Halted,
+	/// The pallet of the call is paused by `pause_pallet`.
+	PalletPaused,
+	/// The call is paused by `pause_transaction`.
+	TransactionPaused,
+	/// The call is allowed, but at most a few calls of it are included per block, see
+	/// `throttle_transaction`.
+	Throttled,
+	/// The call is not allowed by the runtime.
+	NotAllowed,
+	/// The bytes do not decode to a runtime call.
+	InvalidCall,
+}
+
+/// How the calls of an entry listed by `pause_entries` are paused or throttled.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub enum PauseStatus {
+	/// All the calls of the pallet are paused by `pause_pallet`.
+	PalletPaused,
+	/// The call is paused by `pause_transaction`, for Root too if `strict`.
+	TransactionPaused { strict: bool },
+	/// The call is paused by `pause_call` at these indices of its encoding.
+	#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+	CallIndexPaused { pallet_index: u8, call_index: u8 },
+	/// At most `max_per_block` calls are included per block, see `throttle_transaction`.
+	#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+	Throttled { max_per_block: u32 },
+}
+
+/// Gives the calls nested in a runtime call, e.g. the calls of a `Utility::batch`, so that
+/// `ensure_not_strictly_paused` also refuses a strictly paused call wrapped in another one.
+pub trait NestedCalls<Call> {
+	/// Call `f` with each call directly nested in `call`, failing with the first error of `f`.
+	fn try_for_each_nested(
+		call: &Call,
+		f: &mut dyn FnMut(&Call) -> DispatchResult,
+	) -> DispatchResult;
+}
+
+impl<Call> NestedCalls<Call> for () {
+	fn try_for_each_nested(
+		_call: &Call,
+		_f: &mut dyn FnMut(&Call) -> DispatchResult,
+	) -> DispatchResult {
+		Ok(())
+	}
+}
+
+/// A runtime `Call` which refuses to dispatch while it, or a call nested in it, is strictly
+/// paused, whatever the origin.
+///
+/// Root dispatches skip the `BaseCallFilter`, so runtimes give this wrapper instead of `Call`
+/// to the pallets dispatching as Root, e.g. the scheduler, democracy and sudo. It is encoded
+/// and described in the metadata exactly like `Call`.
+#[derive(Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct GuardedCall<Call, T>(pub Call, sp_std::marker::PhantomData<T>);
+
+impl<Call, T> GuardedCall<Call, T> {
+	/// Guard `call`.
+	pub fn new(call: Call) -> Self {
+		Self(call, sp_std::marker::PhantomData)
+	}
+}
+
+impl<Call: Encode, T> Encode for GuardedCall<Call, T> {
+	fn size_hint(&self) -> usize {
+		self.0.size_hint()
+	}
+
+	fn encode_to<O: Output + ?Sized>(&self, dest: &mut O) {
+		self.0.encode_to(dest)
+	}
+
+	fn using_encoded<R, F: FnOnce(&[u8]) -> R>(&self, f: F) -> R {
+		self.0.using_encoded(f)
+	}
+}
+
+impl<Call: Encode, T> EncodeLike for GuardedCall<Call, T> {}
+
+impl<Call: Decode, T> Decode for GuardedCall<Call, T> {
+	fn decode<I: Input>(input: &mut I) -> Result<Self, codec::Error> {
+		Call::decode(input).map(Self::new)
+	}
+}
+
+impl<Call: TypeInfo, T> TypeInfo for GuardedCall<Call, T> {
+	type Identity = Call::Identity;
+
+	fn type_info() -> scale_info::Type {
+		Call::type_info()
+	}
+}
+
+impl<Call, T> Dispatchable for GuardedCall<Call, T>
+where
+	T: Config<KnownCalls = Call>,
+	Call: Dispatchable<Origin = T::Origin, Info = DispatchInfo, PostInfo = PostDispatchInfo>,
+{
+	type Origin = T::Origin;
+	type Config = <Call as Dispatchable>::Config;
+	type Info = DispatchInfo;
+	type PostInfo = PostDispatchInfo;
+
+	fn dispatch(self, origin: T::Origin) -> DispatchResultWithPostInfo {
+		Pallet::<T>::ensure_not_strictly_paused(&self.0)?;
+		self.0.dispatch(origin)
+	}
+}
+
+impl<Call, T> UnfilteredDispatchable for GuardedCall<Call, T>
+where
+	T: Config<KnownCalls = Call>,
+	Call: UnfilteredDispatchable<Origin = T::Origin>,
+{
+	type Origin = T::Origin;
+
+	fn dispatch_bypass_filter(self, origin: T::Origin) -> DispatchResultWithPostInfo {
+		Pallet::<T>::ensure_not_strictly_paused(&self.0)?;
+		self.0.dispatch_bypass_filter(origin)
+	}
+}
+
+impl<Call: GetDispatchInfo, T> GetDispatchInfo for GuardedCall<Call, T> {
+	fn get_dispatch_info(&self) -> DispatchInfo {
+		self.0.get_dispatch_info()
+	}
+}
+
+/// The calls of a pallet, e.g. `frame_system::Call`, convert into the runtime `Call` and then
+/// into a `GuardedCall`.
+impl<Call, T, C> From<C> for GuardedCall<Call, T>
+where
+	C: GetCallName,
+	Call: From<C>,
+{
+	fn from(call: C) -> Self {
+		Self::new(call.into())
+	}
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
 		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
 
-		/// The origin which may set filter.
-		type UpdateOrigin: EnsureOrigin<Self::Origin>;
+		/// The origin which may pause extrinsics and pallets.
+		type PauseOrigin: EnsureOrigin<Self::Origin>;
+
+		/// The origin which may unpause extrinsics and pallets.
+		type UnpauseOrigin: EnsureOrigin<Self::Origin>;
+
+		/// The origin which may dispatch a strictly paused extrinsic by `force_dispatch_paused`.
+		///
+		/// The extrinsic is dispatched with the Root origin, so this should be Root only.
+		type ForceDispatchOrigin: EnsureOrigin<Self::Origin>;
+
+		/// The extrinsics which are still allowed while the chain is halted by `halt`.
+		type HaltAllowList: Contains<<Self as frame_system::Config>::Call>;
+
+		/// The runtime `Call`, whose metadata lists the pallet and extrinsic names
+		/// that may be paused, and which `force_dispatch_paused` dispatches.
+		type KnownCalls: GetCallMetadata
+			+ Parameter
+			+ Dispatchable<Origin = Self::Origin, PostInfo = PostDispatchInfo>
+			+ GetDispatchInfo
+			+ From<frame_system::Call<Self>>;
+
+		/// The calls nested in a `KnownCalls`, which strict pauses also apply to.
+		type NestedCalls: NestedCalls<Self::KnownCalls>;
+
+		/// The pallets of the runtime, i.e. `AllPalletsWithSystem`, whose indices are used by
+		/// `pause_call`.
+		type PalletsInfo: PalletsInfoAccess;
+
+		/// The maximum weight `on_initialize` may spend lifting expired pauses in one block.
+		/// Expired pauses that do not fit are lifted in the following blocks.
+		#[pallet::constant]
+		type MaxExpiryWeight: Get<Weight>;
+
+		/// The maximum length of a pallet or function name.
+		#[pallet::constant]
+		type MaxNameLen: Get<u32>;
+
+		/// The maximum length of the reason given for a pause.
+		#[pallet::constant]
+		type MaxReasonLen: Get<u32>;
+
+		/// The maximum number of extrinsics paused or unpaused in a single batch.
+		#[pallet::constant]
+		type MaxPauseBatch: Get<u32>;
 
 		/// Weight information for the extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
@@ -61,80 +312,905 @@ pub mod pallet {
 		CannotPause,
 		/// invalid character encoding
 		InvalidCharacter,
+		/// expiry block is not in the future
+		InvalidExpiry,
+		/// pallet or function name is longer than `MaxNameLen`
+		NameTooLong,
+		/// batch is longer than `MaxPauseBatch`
+		TooManyCalls,
+		/// pallet or function does not exist in the runtime
+		UnknownCall,
+		/// reason is longer than `MaxReasonLen`
+		ReasonTooLong,
+		/// throttle allows no calls per block, pause the transaction instead
+		InvalidThrottle,
+		/// transaction is not paused
+		NotPaused,
+		/// transaction is strictly paused, even for Root
+		StrictlyPaused,
+		/// transaction is not strictly paused, dispatch it as usual
+		NotStrictlyPaused,
 	}
 
 	#[pallet::event]
 	#[pallet::generate_deposit(fn deposit_event)]
 	pub enum Event<T: Config> {
-		/// Paused transaction . \[pallet_name_bytes, function_name_bytes\]
-		TransactionPaused(Vec<u8>, Vec<u8>),
-		/// Unpaused transaction . \[pallet_name_bytes, function_name_bytes\]
-		TransactionUnpaused(Vec<u8>, Vec<u8>),
+		/// Paused transaction . \[pallet_name_bytes, function_name_bytes, expiry, pause_info\]
+		TransactionPaused(Vec<u8>, Vec<u8>, Option<T::BlockNumber>, PauseInfoOf<T>),
+		/// Unpaused transaction . \[pallet_name_bytes, function_name_bytes, expired\]
+		///
+		/// `expired` is true when the pause was lifted automatically at its expiry block.
+		TransactionUnpaused(Vec<u8>, Vec<u8>, bool),
+		/// Paused all transactions of a pallet . \[pallet_name_bytes\]
+		PalletPaused(Vec<u8>),
+		/// Unpaused all transactions of a pallet . \[pallet_name_bytes\]
+		PalletUnpaused(Vec<u8>),
+		/// Throttled transaction . \[pallet_name_bytes, function_name_bytes, max_per_block\]
+		TransactionThrottled(Vec<u8>, Vec<u8>, u32),
+		/// Unthrottled transaction . \[pallet_name_bytes, function_name_bytes\]
+		TransactionUnthrottled(Vec<u8>, Vec<u8>),
+		/// Paused transaction by call index . \[pallet_index, call_index, pause_info\]
+		CallPaused(u8, u8, PauseInfoOf<T>),
+		/// Unpaused transaction by call index . \[pallet_index, call_index\]
+		CallUnpaused(u8, u8),
+		/// A paused transaction or pallet no longer exists after a runtime upgrade,
+		/// so its pause has no effect . \[pallet_name_bytes, function_name_bytes\]
+		///
+		/// `function_name_bytes` is `None` for a pallet paused by `pause_pallet`.
+		StalePauseEntry(Vec<u8>, Option<Vec<u8>>),
+		/// Made the pause of a transaction strict or not . \[pallet_name_bytes, function_name_bytes, strict\]
+		StrictPauseSet(Vec<u8>, Vec<u8>, bool),
+		/// Dispatched a strictly paused transaction by `force_dispatch_paused` .
+		/// \[pallet_name_bytes, function_name_bytes, result\]
+		PausedCallDispatched(Vec<u8>, Vec<u8>, DispatchResult),
+		/// Halted all transactions but the core and allow-listed ones.
+		EmergencyHalted,
+		/// Resumed all transactions after an emergency halt.
+		EmergencyResumed,
 	}
 
 	/// The paused transaction map
 	///
-	/// map (PalletNameBytes, FunctionNameBytes) => Option<()>
+	/// map (PalletNameBytes, FunctionNameBytes) => Option<PauseInfo>
 	#[pallet::storage]
 	#[pallet::getter(fn paused_transactions)]
 	pub type PausedTransactions<T: Config> =
-		StorageMap<_, Twox64Concat, (Vec<u8>, Vec<u8>), (), OptionQuery>;
+		StorageMap<_, Twox64Concat, (NameOf<T>, NameOf<T>), PauseInfoOf<T>, OptionQuery>;
+
+	/// The expiry block of the time-limited paused transactions
+	///
+	/// map (PalletNameBytes, FunctionNameBytes) => Option<BlockNumber>
+	#[pallet::storage]
+	#[pallet::getter(fn pause_expiry)]
+	pub type PauseExpiries<T: Config> =
+		StorageMap<_, Twox64Concat, (NameOf<T>, NameOf<T>), T::BlockNumber, OptionQuery>;
+
+	/// The time-limited paused transactions to be lifted at a block
+	///
+	/// double_map BlockNumber, (PalletNameBytes, FunctionNameBytes) => Option<()>
+	#[pallet::storage]
+	pub type PauseExpiryQueue<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::BlockNumber,
+		Twox64Concat,
+		(NameOf<T>, NameOf<T>),
+		(),
+		OptionQuery,
+	>;
+
+	/// The earliest block whose expired pauses did not fit in the weight limit
+	/// and are still in `PauseExpiryQueue`
+	#[pallet::storage]
+	pub type ExpiryCursor<T: Config> = StorageValue<_, T::BlockNumber, OptionQuery>;
+
+	/// The paused pallet map
+	///
+	/// map PalletNameBytes => Option<()>
+	#[pallet::storage]
+	#[pallet::getter(fn paused_pallets)]
+	pub type PausedPallets<T: Config> = StorageMap<_, Twox64Concat, NameOf<T>, (), OptionQuery>;
+
+	/// The paused transaction map keyed by the indices of the encoded call
+	///
+	/// map (PalletIndex, CallIndex) => Option<PauseInfo>
+	#[pallet::storage]
+	#[pallet::getter(fn paused_call_indices)]
+	pub type PausedCallIndices<T: Config> =
+		StorageMap<_, Twox64Concat, (u8, u8), PauseInfoOf<T>, OptionQuery>;
+
+	/// The number of entries in `PausedCallIndices`, so that filtering a call only looks up
+	/// its index while some call is paused by it
+	///
+	/// value u32
+	#[pallet::storage]
+	pub type PausedCallIndexCount<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// The maximum number of calls per block of the throttled transactions
+	///
+	/// map (PalletNameBytes, FunctionNameBytes) => Option<u32>
+	#[pallet::storage]
+	#[pallet::getter(fn throttled_transactions)]
+	pub type ThrottledTransactions<T: Config> =
+		StorageMap<_, Twox64Concat, (NameOf<T>, NameOf<T>), u32, OptionQuery>;
+
+	/// The number of calls of the throttled transactions in the last block they were called
+	///
+	/// map (PalletNameBytes, FunctionNameBytes) => (BlockNumber, u32)
+	#[pallet::storage]
+	pub type ThrottleCounts<T: Config> =
+		StorageMap<_, Twox64Concat, (NameOf<T>, NameOf<T>), (T::BlockNumber, u32), OptionQuery>;
+
+	/// Whether all transactions but the core and allow-listed ones are halted
+	///
+	/// value bool
+	#[pallet::storage]
+	#[pallet::getter(fn emergency_halt)]
+	pub type EmergencyHalt<T: Config> = StorageValue<_, bool, ValueQuery>;
 
 	#[pallet::pallet]
-	#[pallet::without_storage_info]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+		fn on_initialize(now: T::BlockNumber) -> Weight {
+			let mut weight = T::WeightInfo::on_initialize(0);
+			let per_item = T::WeightInfo::on_initialize(1).saturating_sub(weight);
+			let per_block = T::DbWeight::get().reads(1);
+			let limit = T::MaxExpiryWeight::get();
+
+			let cursor = ExpiryCursor::<T>::get();
+			let mut block = cursor.unwrap_or(now);
+			loop {
+				for key in PauseExpiryQueue::<T>::iter_key_prefix(block) {
+					if weight.saturating_add(per_item) > limit {
+						// Lift the rest of the expired pauses in the following blocks.
+						ExpiryCursor::<T>::put(block);
+						return weight;
+					}
+					weight = weight.saturating_add(per_item);
+					PauseExpiryQueue::<T>::remove(block, &key);
+
+					// Entries are left in the queue when a pause is lifted or rescheduled,
+					// so only lift it if it still expires by now.
+					if matches!(Self::pause_expiry(&key), Some(expiry) if expiry <= now) {
+						PauseExpiries::<T>::remove(&key);
+						PausedTransactions::<T>::remove(&key);
+						Self::deposit_event(Event::TransactionUnpaused(
+							key.0.into_inner(),
+							key.1.into_inner(),
+							true,
+						));
+					}
+				}
+				if block >= now {
+					break;
+				}
+				block = block.saturating_add(One::one());
+				if weight.saturating_add(per_block) > limit {
+					ExpiryCursor::<T>::put(block);
+					return weight;
+				}
+				weight = weight.saturating_add(per_block);
+			}
+
+			if cursor.is_some() {
+				ExpiryCursor::<T>::kill();
+			}
+			weight
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			migrations::v1::migrate::<T>()
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<(), &'static str> {
+			migrations::v1::pre_migrate::<T>()
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			migrations::v1::post_migrate::<T>()
+		}
+	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Pause an extrinsic by passing the extrinsic and corresponding pallet names.
 		/// Use names as they are written in the source code of the pallet.
+		///
+		/// If `expiry` is given, the pause is lifted automatically at that block.
+		/// The `reason` is stored with the pause, together with the block and the origin.
+		/// Pausing an already paused extrinsic updates its expiry and reason.
 		#[pallet::weight(T::WeightInfo::pause_transaction())]
 		#[transactional]
 		pub fn pause_transaction(
 			origin: OriginFor<T>,
 			pallet_name: Vec<u8>,
 			function_name: Vec<u8>,
+			expiry: Option<T::BlockNumber>,
+			reason: Vec<u8>,
 		) -> DispatchResult {
-			T::UpdateOrigin::ensure_origin(origin)?;
+			T::PauseOrigin::ensure_origin(origin.clone())?;
+			let info = Self::pause_info(origin, reason)?;
+			Self::do_pause_transaction(pallet_name, function_name, expiry, info)
+		}
 
-			// not allowed to pause calls of this pallet to ensure safe
-			let pallet_name_string =
-				sp_std::str::from_utf8(&pallet_name).map_err(|_| Error::<T>::InvalidCharacter)?;
+		/// Unpause an extrinsic by passing the extrinsic and corresponding pallet names.
+		/// Use names as they are written in the source code of the pallet.
+		#[pallet::weight(T::WeightInfo::unpause_transaction())]
+		#[transactional]
+		pub fn unpause_transaction(
+			origin: OriginFor<T>,
+			pallet_name: Vec<u8>,
+			function_name: Vec<u8>,
+		) -> DispatchResult {
+			T::UnpauseOrigin::ensure_origin(origin)?;
+			Self::do_unpause_transaction(pallet_name, function_name)
+		}
+
+		/// Pause all the extrinsics of a pallet by passing the pallet name.
+		/// Use the name as it is declared in `construct_runtime!`.
+		#[pallet::weight(T::WeightInfo::pause_pallet())]
+		#[transactional]
+		pub fn pause_pallet(origin: OriginFor<T>, pallet_name: Vec<u8>) -> DispatchResult {
+			T::PauseOrigin::ensure_origin(origin)?;
+
+			Self::ensure_pallet_can_pause(&pallet_name)?;
+			let name = Self::bounded_name(pallet_name)?;
+			Self::ensure_call_exists(&name, None)?;
+
+			PausedPallets::<T>::mutate_exists(&name, |maybe_paused| {
+				if maybe_paused.is_none() {
+					*maybe_paused = Some(());
+					Self::deposit_event(Event::PalletPaused(name.to_vec()));
+				}
+			});
+			Ok(())
+		}
+
+		/// Unpause all the extrinsics of a pallet by passing the pallet name.
+		/// Single extrinsics paused by `pause_transaction` stay paused.
+		#[pallet::weight(T::WeightInfo::unpause_pallet())]
+		#[transactional]
+		pub fn unpause_pallet(origin: OriginFor<T>, pallet_name: Vec<u8>) -> DispatchResult {
+			T::UnpauseOrigin::ensure_origin(origin)?;
+			let name = Self::bounded_name(pallet_name)?;
+			if PausedPallets::<T>::take(&name).is_some() {
+				Self::deposit_event(Event::PalletUnpaused(name.into_inner()));
+			};
+			Ok(())
+		}
+
+		/// Pause a batch of extrinsics by passing pairs of pallet and extrinsic names,
+		/// at most `MaxPauseBatch` of them.
+		/// All of them are paused with the same `reason`.
+		/// The whole batch fails if any of the extrinsics cannot be paused.
+		#[pallet::weight(T::WeightInfo::pause_transactions(calls.len() as u32))]
+		#[transactional]
+		pub fn pause_transactions(
+			origin: OriginFor<T>,
+			calls: Vec<(Vec<u8>, Vec<u8>)>,
+			reason: Vec<u8>,
+		) -> DispatchResult {
+			T::PauseOrigin::ensure_origin(origin.clone())?;
 			ensure!(
-				pallet_name_string != <Self as PalletInfoAccess>::name(),
-				Error::<T>::CannotPause
+				calls.len() as u32 <= T::MaxPauseBatch::get(),
+				Error::<T>::TooManyCalls
 			);
+			let info = Self::pause_info(origin, reason)?;
+			for (pallet_name, function_name) in calls {
+				Self::do_pause_transaction(pallet_name, function_name, None, info.clone())?;
+			}
+			Ok(())
+		}
 
-			PausedTransactions::<T>::mutate_exists(
-				(pallet_name.clone(), function_name.clone()),
-				|maybe_paused| {
-					if maybe_paused.is_none() {
-						*maybe_paused = Some(());
-						Self::deposit_event(Event::TransactionPaused(pallet_name, function_name));
-					}
-				},
+		/// Unpause a batch of extrinsics by passing pairs of pallet and extrinsic names,
+		/// at most `MaxPauseBatch` of them.
+		#[pallet::weight(T::WeightInfo::unpause_transactions(calls.len() as u32))]
+		#[transactional]
+		pub fn unpause_transactions(
+			origin: OriginFor<T>,
+			calls: Vec<(Vec<u8>, Vec<u8>)>,
+		) -> DispatchResult {
+			T::UnpauseOrigin::ensure_origin(origin)?;
+			ensure!(
+				calls.len() as u32 <= T::MaxPauseBatch::get(),
+				Error::<T>::TooManyCalls
 			);
+			for (pallet_name, function_name) in calls {
+				Self::do_unpause_transaction(pallet_name, function_name)?;
+			}
 			Ok(())
 		}
 
-		/// Unpause an extrinsic by passing the extrinsic and corresponding pallet names.
+		/// Halt all the extrinsics except the core ones and those in `HaltAllowList`.
+		#[pallet::weight(T::WeightInfo::halt())]
+		#[transactional]
+		pub fn halt(origin: OriginFor<T>) -> DispatchResult {
+			T::PauseOrigin::ensure_origin(origin)?;
+			if !EmergencyHalt::<T>::get() {
+				EmergencyHalt::<T>::put(true);
+				Self::deposit_event(Event::EmergencyHalted);
+			}
+			Ok(())
+		}
+
+		/// Resume all the extrinsics after `halt`.
+		/// Extrinsics and pallets which are paused on their own stay paused.
+		#[pallet::weight(T::WeightInfo::resume())]
+		#[transactional]
+		pub fn resume(origin: OriginFor<T>) -> DispatchResult {
+			T::UnpauseOrigin::ensure_origin(origin)?;
+			if EmergencyHalt::<T>::take() {
+				Self::deposit_event(Event::EmergencyResumed);
+			}
+			Ok(())
+		}
+
+		/// Pause an extrinsic by its pallet and call index, taken from the encoded `call`.
+		/// The arguments of `call` are ignored.
+		///
+		/// Unlike `pause_transaction`, the pause is kept when the pallet or the extrinsic
+		/// is renamed, as long as its index does not change.
+		#[pallet::weight(T::WeightInfo::pause_call())]
+		#[transactional]
+		pub fn pause_call(
+			origin: OriginFor<T>,
+			call: Box<T::KnownCalls>,
+			reason: Vec<u8>,
+		) -> DispatchResult {
+			T::PauseOrigin::ensure_origin(origin.clone())?;
+			let CallMetadata { pallet_name, .. } = call.get_call_metadata();
+			Self::ensure_pallet_can_pause(pallet_name.as_bytes())?;
+			let (pallet_index, call_index) =
+				Self::call_index(&*call).ok_or(Error::<T>::UnknownCall)?;
+			let info = Self::pause_info(origin, reason)?;
+			Self::do_pause_call(pallet_index, call_index, info);
+			Ok(())
+		}
+
+		/// Unpause an extrinsic paused by `pause_call`.
+		#[pallet::weight(T::WeightInfo::unpause_call())]
+		#[transactional]
+		pub fn unpause_call(
+			origin: OriginFor<T>,
+			pallet_index: u8,
+			call_index: u8,
+		) -> DispatchResult {
+			T::UnpauseOrigin::ensure_origin(origin)?;
+			if PausedCallIndices::<T>::take((pallet_index, call_index)).is_some() {
+				PausedCallIndexCount::<T>::mutate(|count| *count = count.saturating_sub(1));
+				Self::deposit_event(Event::CallUnpaused(pallet_index, call_index));
+			}
+			Ok(())
+		}
+
+		/// Make the pause of a paused extrinsic strict, or lift its strictness.
+		///
+		/// Calls dispatched by Root, e.g. by the scheduler or sudo, skip the runtime
+		/// `BaseCallFilter` and are only stopped by a strict pause, provided the runtime
+		/// dispatches them through a guard calling `ensure_not_strictly_paused`, which also
+		/// checks the calls nested in them, e.g. in a `Utility::batch`.
+		/// Making a pause strict requires `PauseOrigin`, lifting it `UnpauseOrigin`.
+		#[pallet::weight(T::WeightInfo::set_strict_pause())]
+		#[transactional]
+		pub fn set_strict_pause(
+			origin: OriginFor<T>,
+			pallet_name: Vec<u8>,
+			function_name: Vec<u8>,
+			strict: bool,
+		) -> DispatchResult {
+			if strict {
+				T::PauseOrigin::ensure_origin(origin)?;
+			} else {
+				T::UnpauseOrigin::ensure_origin(origin)?;
+			}
+			let key = (
+				Self::bounded_name(pallet_name)?,
+				Self::bounded_name(function_name)?,
+			);
+			PausedTransactions::<T>::try_mutate(&key, |maybe_info| -> DispatchResult {
+				let info = maybe_info.as_mut().ok_or(Error::<T>::NotPaused)?;
+				info.strict = strict;
+				Ok(())
+			})?;
+			Self::deposit_event(Event::StrictPauseSet(
+				key.0.into_inner(),
+				key.1.into_inner(),
+				strict,
+			));
+			Ok(())
+		}
+
+		/// Dispatch a strictly paused extrinsic with the Root origin, overriding its pause.
+		/// Requires `ForceDispatchOrigin`.
+		///
+		/// Any other call must be dispatched as usual. The changes of a dispatch which fails
+		/// are reverted, and its error is reported in `PausedCallDispatched`.
+		#[pallet::weight({
+			let dispatch_info = call.get_dispatch_info();
+			(
+				T::WeightInfo::force_dispatch_paused()
+					.saturating_add(dispatch_info.weight),
+				dispatch_info.class,
+			)
+		})]
+		#[transactional]
+		pub fn force_dispatch_paused(
+			origin: OriginFor<T>,
+			call: Box<T::KnownCalls>,
+		) -> DispatchResultWithPostInfo {
+			T::ForceDispatchOrigin::ensure_origin(origin)?;
+			ensure!(
+				Self::is_strictly_paused(&*call),
+				Error::<T>::NotStrictlyPaused
+			);
+
+			let CallMetadata {
+				function_name,
+				pallet_name,
+			} = call.get_call_metadata();
+			let dispatch_info = call.get_dispatch_info();
+			let result = with_transaction(|| {
+				let result = (*call).dispatch(frame_system::RawOrigin::Root.into());
+				if result.is_ok() {
+					TransactionOutcome::Commit(result)
+				} else {
+					TransactionOutcome::Rollback(result)
+				}
+			});
+			Self::deposit_event(Event::PausedCallDispatched(
+				pallet_name.as_bytes().to_vec(),
+				function_name.as_bytes().to_vec(),
+				result.map(|_| ()).map_err(|e| e.error),
+			));
+			Ok(Some(
+				T::WeightInfo::force_dispatch_paused()
+					.saturating_add(extract_actual_weight(&result, &dispatch_info)),
+			)
+			.into())
+		}
+
+		/// Allow at most `max_per_block` signed calls of an extrinsic per block.
 		/// Use names as they are written in the source code of the pallet.
-		#[pallet::weight(T::WeightInfo::unpause_transaction())]
+		///
+		/// Calls beyond the cap are rejected by the `CheckTxPause` signed extension, both at
+		/// transaction validation and at inclusion in a block, and may be submitted again in a
+		/// later block. Calls nested in other calls are not counted.
+		#[pallet::weight(T::WeightInfo::throttle_transaction())]
 		#[transactional]
-		pub fn unpause_transaction(
+		pub fn throttle_transaction(
 			origin: OriginFor<T>,
 			pallet_name: Vec<u8>,
 			function_name: Vec<u8>,
+			max_per_block: u32,
 		) -> DispatchResult {
-			T::UpdateOrigin::ensure_origin(origin)?;
-			if PausedTransactions::<T>::take((&pallet_name, &function_name)).is_some() {
-				Self::deposit_event(Event::TransactionUnpaused(pallet_name, function_name));
-			};
+			T::PauseOrigin::ensure_origin(origin)?;
+			ensure!(max_per_block > 0, Error::<T>::InvalidThrottle);
+
+			Self::ensure_pallet_can_pause(&pallet_name)?;
+			let key = (
+				Self::bounded_name(pallet_name)?,
+				Self::bounded_name(function_name)?,
+			);
+			Self::ensure_call_exists(&key.0, Some(key.1.as_slice()))?;
+
+			ThrottledTransactions::<T>::insert(&key, max_per_block);
+			Self::deposit_event(Event::TransactionThrottled(
+				key.0.into_inner(),
+				key.1.into_inner(),
+				max_per_block,
+			));
 			Ok(())
 		}
+
+		/// Lift the throttle of an extrinsic set by `throttle_transaction`.
+		#[pallet::weight(T::WeightInfo::unthrottle_transaction())]
+		#[transactional]
+		pub fn unthrottle_transaction(
+			origin: OriginFor<T>,
+			pallet_name: Vec<u8>,
+			function_name: Vec<u8>,
+		) -> DispatchResult {
+			T::UnpauseOrigin::ensure_origin(origin)?;
+			let key = (
+				Self::bounded_name(pallet_name)?,
+				Self::bounded_name(function_name)?,
+			);
+			if ThrottledTransactions::<T>::take(&key).is_some() {
+				ThrottleCounts::<T>::remove(&key);
+				Self::deposit_event(Event::TransactionUnthrottled(
+					key.0.into_inner(),
+					key.1.into_inner(),
+				));
+			}
+			Ok(())
+		}
+	}
+}
+
+/// Pallets which are always allowed by the runtime `BaseFilter`, so pausing them is refused.
+pub const CORE_PALLETS: [&str; 3] = ["System", "Timestamp", "ParachainSystem"];
+
+impl<T: Config> Pallet<T> {
+	/// The number of blocks until a time-limited pause is lifted.
+	///
+	/// Returns `None` if the extrinsic is not paused or is paused indefinitely.
+	pub fn remaining_pause_duration(
+		pallet_name: &[u8],
+		function_name: &[u8],
+	) -> Option<T::BlockNumber> {
+		let key = (
+			Self::bounded_name(pallet_name.to_vec()).ok()?,
+			Self::bounded_name(function_name.to_vec()).ok()?,
+		);
+		Self::pause_expiry(key)
+			.map(|expiry| expiry.saturating_sub(frame_system::Pallet::<T>::block_number()))
+	}
+
+	/// All the pauses and throttles as `(pallet_name, function_name, status)`.
+	///
+	/// The function name is empty for a paused pallet. The names of a call paused by
+	/// `pause_call` are looked up from its indices, and are empty if these do not resolve.
+	pub fn pause_entries() -> Vec<(Vec<u8>, Vec<u8>, PauseStatus)>
+	where
+		T::Call: GetCallMetadata,
+	{
+		let pallets = PausedPallets::<T>::iter_keys().map(|pallet_name| {
+			(
+				pallet_name.into_inner(),
+				Vec::new(),
+				PauseStatus::PalletPaused,
+			)
+		});
+		let transactions =
+			PausedTransactions::<T>::iter().map(|((pallet_name, function_name), info)| {
+				(
+					pallet_name.into_inner(),
+					function_name.into_inner(),
+					PauseStatus::TransactionPaused {
+						strict: info.strict,
+					},
+				)
+			});
+		let call_indices = PausedCallIndices::<T>::iter_keys().map(|(pallet_index, call_index)| {
+			let (pallet_name, function_name) =
+				Self::call_names(pallet_index, call_index).unwrap_or_default();
+			(
+				pallet_name,
+				function_name,
+				PauseStatus::CallIndexPaused {
+					pallet_index,
+					call_index,
+				},
+			)
+		});
+		let throttles = ThrottledTransactions::<T>::iter().map(
+			|((pallet_name, function_name), max_per_block)| {
+				(
+					pallet_name.into_inner(),
+					function_name.into_inner(),
+					PauseStatus::Throttled { max_per_block },
+				)
+			},
+		);
+		pallets
+			.chain(transactions)
+			.chain(call_indices)
+			.chain(throttles)
+			.collect()
+	}
+
+	/// The pallet and function names of the call at `pallet_index` and `call_index`, the
+	/// reverse of `call_index`.
+	fn call_names(pallet_index: u8, call_index: u8) -> Option<(Vec<u8>, Vec<u8>)>
+	where
+		T::Call: GetCallMetadata,
+	{
+		let pallet_name = T::PalletsInfo::infos()
+			.into_iter()
+			.find(|info| info.index == pallet_index as usize)?
+			.name;
+		let function_name = T::Call::get_call_names(pallet_name).get(call_index as usize)?;
+		Some((
+			pallet_name.as_bytes().to_vec(),
+			function_name.as_bytes().to_vec(),
+		))
+	}
+
+	/// `Throttled` if `call` is throttled by `throttle_transaction`.
+	///
+	/// The runtime `BaseFilter` lets such a call through, but `CheckTxPause` rejects it once
+	/// the cap of its throttle is reached in a block.
+	pub fn throttle_status(call: &T::Call) -> Option<CallFilterStatus>
+	where
+		T::Call: GetCallMetadata,
+	{
+		Self::throttle_of(call).map(|_| CallFilterStatus::Throttled)
+	}
+
+	/// Why this pallet filters `call`, if it does.
+	///
+	/// The runtime `BaseFilter` uses this to tell its own rejections from the pauses.
+	pub fn pause_status(call: &T::Call) -> Option<CallFilterStatus>
+	where
+		T::Call: GetCallMetadata,
+	{
+		if EmergencyHaltFilter::<T>::contains(call) {
+			Some(CallFilterStatus::Halted)
+		} else if PausedTransactionFilter::<T>::contains(call) {
+			let CallMetadata { pallet_name, .. } = call.get_call_metadata();
+			let pallet_paused = NameOf::<T>::try_from(pallet_name.as_bytes().to_vec())
+				.map_or(false, |name| PausedPallets::<T>::contains_key(name));
+			if pallet_paused {
+				Some(CallFilterStatus::PalletPaused)
+			} else {
+				Some(CallFilterStatus::TransactionPaused)
+			}
+		} else {
+			None
+		}
+	}
+
+	/// Whether `call` is paused by `pause_transaction` with a strict pause.
+	pub fn is_strictly_paused(call: &impl GetCallMetadata) -> bool {
+		let CallMetadata {
+			function_name,
+			pallet_name,
+		} = call.get_call_metadata();
+		match (
+			NameOf::<T>::try_from(pallet_name.as_bytes().to_vec()),
+			NameOf::<T>::try_from(function_name.as_bytes().to_vec()),
+		) {
+			(Ok(pallet_name), Ok(function_name)) => {
+				PausedTransactions::<T>::get((pallet_name, function_name))
+					.map_or(false, |info| info.strict)
+			}
+			_ => false,
+		}
+	}
+
+	/// Fail with `StrictlyPaused` if `call`, or any call nested in it, is strictly paused.
+	///
+	/// Runtimes call this before the Root dispatches which skip `BaseCallFilter`.
+	pub fn ensure_not_strictly_paused(call: &T::KnownCalls) -> DispatchResult {
+		ensure!(!Self::is_strictly_paused(call), Error::<T>::StrictlyPaused);
+		T::NestedCalls::try_for_each_nested(call, &mut |nested| {
+			Self::ensure_not_strictly_paused(nested)
+		})
+	}
+
+	/// The pallet and call index of `call`, i.e. the first two bytes of its encoding.
+	///
+	/// They are looked up from the metadata of `call`, so that filtering a call never encodes it.
+	pub fn call_index<C: GetCallMetadata>(call: &C) -> Option<(u8, u8)> {
+		let CallMetadata {
+			function_name,
+			pallet_name,
+		} = call.get_call_metadata();
+		let pallet_index = T::PalletsInfo::infos()
+			.into_iter()
+			.find(|info| info.name == pallet_name)?
+			.index;
+		// calls are indexed in the order they are declared in
+		let call_index = C::get_call_names(pallet_name)
+			.iter()
+			.position(|name| *name == function_name)?;
+		Some((pallet_index.try_into().ok()?, call_index.try_into().ok()?))
+	}
+
+	fn do_pause_call(pallet_index: u8, call_index: u8, info: PauseInfoOf<T>) {
+		if !PausedCallIndices::<T>::contains_key((pallet_index, call_index)) {
+			PausedCallIndexCount::<T>::mutate(|count| *count = count.saturating_add(1));
+		}
+		PausedCallIndices::<T>::insert((pallet_index, call_index), &info);
+		Self::deposit_event(Event::CallPaused(pallet_index, call_index, info));
+	}
+
+	/// Emit `StalePauseEntry` for every paused transaction and pallet whose name does not
+	/// resolve in the runtime any more, e.g. after it was renamed.
+	fn reconcile_pause_entries() -> Weight {
+		let mut reads: Weight = 0;
+		let mut events: Weight = 0;
+		for (pallet_name, function_name) in PausedTransactions::<T>::iter_keys() {
+			reads += 1;
+			if Self::ensure_call_exists(&pallet_name, Some(function_name.as_slice())).is_err() {
+				events += 1;
+				Self::deposit_event(Event::StalePauseEntry(
+					pallet_name.into_inner(),
+					Some(function_name.into_inner()),
+				));
+			}
+		}
+		for pallet_name in PausedPallets::<T>::iter_keys() {
+			reads += 1;
+			if Self::ensure_call_exists(&pallet_name, None).is_err() {
+				events += 1;
+				Self::deposit_event(Event::StalePauseEntry(pallet_name.into_inner(), None));
+			}
+		}
+		T::DbWeight::get().reads_writes(reads, events)
+	}
+
+	/// The number of calls of a throttled transaction in the current block.
+	pub fn throttle_count(key: &(NameOf<T>, NameOf<T>)) -> u32 {
+		match ThrottleCounts::<T>::get(key) {
+			Some((block, count)) if block == frame_system::Pallet::<T>::block_number() => count,
+			// counted in an earlier block
+			_ => 0,
+		}
+	}
+
+	/// The throttle key and the cap of `call`, if it is throttled.
+	fn throttle_of(call: &T::Call) -> Option<((NameOf<T>, NameOf<T>), u32)>
+	where
+		T::Call: GetCallMetadata,
+	{
+		let CallMetadata {
+			function_name,
+			pallet_name,
+		} = call.get_call_metadata();
+		// names longer than `MaxNameLen` can never be throttled
+		let key = (
+			NameOf::<T>::try_from(pallet_name.as_bytes().to_vec()).ok()?,
+			NameOf::<T>::try_from(function_name.as_bytes().to_vec()).ok()?,
+		);
+		let max_per_block = ThrottledTransactions::<T>::get(&key)?;
+		Some((key, max_per_block))
+	}
+
+	/// Fail with `InvalidTransaction::ExhaustsResources` if `call` is throttled and the cap
+	/// of its throttle is already reached in this block.
+	fn ensure_below_throttle(call: &T::Call) -> Result<(), TransactionValidityError>
+	where
+		T::Call: GetCallMetadata,
+	{
+		match Self::throttle_of(call) {
+			Some((key, max_per_block)) if Self::throttle_count(&key) >= max_per_block => {
+				Err(InvalidTransaction::ExhaustsResources.into())
+			}
+			_ => Ok(()),
+		}
+	}
+
+	/// Count `call` against its throttle, if it has one.
+	///
+	/// Fails with `InvalidTransaction::ExhaustsResources` if the cap of the throttle
+	/// is already reached in this block.
+	fn note_throttled_call(call: &T::Call) -> Result<(), TransactionValidityError>
+	where
+		T::Call: GetCallMetadata,
+	{
+		if let Some((key, max_per_block)) = Self::throttle_of(call) {
+			let count = Self::throttle_count(&key);
+			if count >= max_per_block {
+				return Err(InvalidTransaction::ExhaustsResources.into());
+			}
+			ThrottleCounts::<T>::insert(
+				&key,
+				(frame_system::Pallet::<T>::block_number(), count + 1),
+			);
+		}
+		Ok(())
+	}
+
+	/// The `PauseInfo` of a pause made now by `origin` for `reason`.
+	fn pause_info(origin: OriginFor<T>, reason: Vec<u8>) -> Result<PauseInfoOf<T>, Error<T>> {
+		let origin: Result<frame_system::RawOrigin<T::AccountId>, OriginFor<T>> = origin.into();
+		let paused_by = match origin {
+			Ok(frame_system::RawOrigin::Root) => PausedBy::Root,
+			Ok(frame_system::RawOrigin::Signed(who)) => PausedBy::Signed(who),
+			_ => PausedBy::Other,
+		};
+		Ok(PauseInfo {
+			reason: reason.try_into().map_err(|_| Error::<T>::ReasonTooLong)?,
+			paused_at: frame_system::Pallet::<T>::block_number(),
+			paused_by,
+			strict: false,
+		})
+	}
+
+	fn do_pause_transaction(
+		pallet_name: Vec<u8>,
+		function_name: Vec<u8>,
+		expiry: Option<T::BlockNumber>,
+		mut info: PauseInfoOf<T>,
+	) -> DispatchResult {
+		Self::ensure_pallet_can_pause(&pallet_name)?;
+		if let Some(expiry) = expiry {
+			ensure!(
+				expiry > frame_system::Pallet::<T>::block_number(),
+				Error::<T>::InvalidExpiry
+			);
+		}
+
+		let key = (
+			Self::bounded_name(pallet_name)?,
+			Self::bounded_name(function_name)?,
+		);
+		Self::ensure_call_exists(&key.0, Some(key.1.as_slice()))?;
+		if let Some(paused) = PausedTransactions::<T>::get(&key) {
+			if paused.reason == info.reason && PauseExpiries::<T>::get(&key) == expiry {
+				return Ok(());
+			}
+			// pausing again never lifts the strictness of a pause
+			info.strict = paused.strict;
+		}
+
+		PausedTransactions::<T>::insert(&key, &info);
+		match expiry {
+			Some(expiry) => {
+				PauseExpiries::<T>::insert(&key, expiry);
+				PauseExpiryQueue::<T>::insert(expiry, &key, ());
+			}
+			None => PauseExpiries::<T>::remove(&key),
+		}
+		Self::deposit_event(Event::TransactionPaused(
+			key.0.into_inner(),
+			key.1.into_inner(),
+			expiry,
+			info,
+		));
+		Ok(())
+	}
+
+	fn do_unpause_transaction(pallet_name: Vec<u8>, function_name: Vec<u8>) -> DispatchResult {
+		let key = (
+			Self::bounded_name(pallet_name)?,
+			Self::bounded_name(function_name)?,
+		);
+		if PausedTransactions::<T>::take(&key).is_some() {
+			PauseExpiries::<T>::remove(&key);
+			Self::deposit_event(Event::TransactionUnpaused(
+				key.0.into_inner(),
+				key.1.into_inner(),
+				false,
+			));
+		};
+		Ok(())
+	}
+
+	fn bounded_name(name: Vec<u8>) -> Result<NameOf<T>, Error<T>> {
+		NameOf::<T>::try_from(name).map_err(|_| Error::<T>::NameTooLong)
+	}
+
+	/// Ensure the pallet, and the function if given, are known to the runtime `Call`.
+	fn ensure_call_exists(pallet_name: &[u8], function_name: Option<&[u8]>) -> DispatchResult {
+		let pallet_name_string =
+			sp_std::str::from_utf8(pallet_name).map_err(|_| Error::<T>::InvalidCharacter)?;
+		ensure!(
+			T::KnownCalls::get_module_names().contains(&pallet_name_string),
+			Error::<T>::UnknownCall
+		);
+		if let Some(function_name) = function_name {
+			let function_name_string =
+				sp_std::str::from_utf8(function_name).map_err(|_| Error::<T>::InvalidCharacter)?;
+			ensure!(
+				T::KnownCalls::get_call_names(pallet_name_string).contains(&function_name_string),
+				Error::<T>::UnknownCall
+			);
+		}
+		Ok(())
+	}
+
+	/// Ensure `pallet_name` is neither this pallet nor a core pallet.
+	fn ensure_pallet_can_pause(pallet_name: &[u8]) -> DispatchResult {
+		// not allowed to pause calls of this pallet to ensure safe
+		let pallet_name_string =
+			sp_std::str::from_utf8(pallet_name).map_err(|_| Error::<T>::InvalidCharacter)?;
+		ensure!(
+			pallet_name_string != <Self as PalletInfoAccess>::name(),
+			Error::<T>::CannotPause
+		);
+		// core calls are never filtered, so a pause on them would be misleading
+		ensure!(
+			!CORE_PALLETS.contains(&pallet_name_string),
+			Error::<T>::CannotPause
+		);
+		Ok(())
 	}
 }
 
@@ -148,6 +1224,106 @@ where
 			function_name,
 			pallet_name,
 		} = call.get_call_metadata();
-		PausedTransactions::<T>::contains_key((pallet_name.as_bytes(), function_name.as_bytes()))
+		// names longer than `MaxNameLen` can never be paused
+		let paused_by_name = match NameOf::<T>::try_from(pallet_name.as_bytes().to_vec()) {
+			Ok(pallet_name) => {
+				PausedPallets::<T>::contains_key(&pallet_name)
+					|| match NameOf::<T>::try_from(function_name.as_bytes().to_vec()) {
+						Ok(function_name) => {
+							PausedTransactions::<T>::contains_key((pallet_name, function_name))
+						}
+						Err(_) => false,
+					}
+			}
+			Err(_) => false,
+		};
+		// looking up the index scans the pallets and calls, skip it while nothing is paused by it
+		paused_by_name
+			|| (PausedCallIndexCount::<T>::get() > 0
+				&& Pallet::<T>::call_index(call)
+					.map_or(false, |index| PausedCallIndices::<T>::contains_key(index)))
+	}
+}
+
+/// Matches every call which is halted by `halt`, i.e. all calls not in `HaltAllowList`
+/// while `EmergencyHalt` is set. The runtime `BaseFilter` checks the core calls first.
+pub struct EmergencyHaltFilter<T>(sp_std::marker::PhantomData<T>);
+impl<T: Config> Contains<T::Call> for EmergencyHaltFilter<T> {
+	fn contains(call: &T::Call) -> bool {
+		EmergencyHalt::<T>::get() && !T::HaltAllowList::contains(call)
+	}
+}
+
+/// Rejects calls which the runtime `BaseCallFilter` would filter, e.g. paused or halted ones,
+/// at transaction validation, so they never enter the pool or a block and pay no fee.
+///
+/// It also counts the calls of throttled transactions, and rejects those over the cap
+/// of the current block.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct CheckTxPause<T: Config + Send + Sync>(sp_std::marker::PhantomData<T>);
+
+impl<T: Config + Send + Sync> CheckTxPause<T> {
+	/// Create new `SignedExtension` to check the runtime `BaseCallFilter`.
+	pub fn new() -> Self {
+		Self(sp_std::marker::PhantomData)
+	}
+}
+
+impl<T: Config + Send + Sync> Default for CheckTxPause<T> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<T: Config + Send + Sync> sp_std::fmt::Debug for CheckTxPause<T> {
+	#[cfg(feature = "std")]
+	fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		write!(f, "CheckTxPause")
+	}
+
+	#[cfg(not(feature = "std"))]
+	fn fmt(&self, _: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		Ok(())
+	}
+}
+
+impl<T: Config + Send + Sync> SignedExtension for CheckTxPause<T>
+where
+	T::Call: GetCallMetadata,
+{
+	const IDENTIFIER: &'static str = "CheckTxPause";
+	type AccountId = T::AccountId;
+	type Call = T::Call;
+	type AdditionalSigned = ();
+	type Pre = ();
+
+	fn additional_signed(&self) -> Result<(), TransactionValidityError> {
+		Ok(())
+	}
+
+	fn validate(
+		&self,
+		_who: &Self::AccountId,
+		call: &Self::Call,
+		_info: &DispatchInfoOf<Self::Call>,
+		_len: usize,
+	) -> TransactionValidity {
+		if !<T as frame_system::Config>::BaseCallFilter::contains(call) {
+			return Err(InvalidTransaction::Call.into());
+		}
+		Pallet::<T>::ensure_below_throttle(call)?;
+		Ok(ValidTransaction::default())
+	}
+
+	fn pre_dispatch(
+		self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		len: usize,
+	) -> Result<(), TransactionValidityError> {
+		self.validate(who, call, info, len)?;
+		Pallet::<T>::note_throttled_call(call)
 	}
 }
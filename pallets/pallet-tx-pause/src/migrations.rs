@@ -0,0 +1,131 @@
+// Copyright 2020-2022 Manta Network.
+// This file is part of Manta.
+//
+// Manta is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Manta is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Manta.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Storage migrations for the transaction pause pallet.
+
+use super::*;
+use frame_support::{
+	storage::migration::{storage_key_iter, take_storage_item},
+	traits::{GetStorageVersion, OnRuntimeUpgrade},
+};
+
+/// Emit `StalePauseEntry` for every paused transaction and pallet whose name does not
+/// resolve in the runtime any more.
+///
+/// Add it to the migrations of the runtime `Executive`, so that every upgrade which renames
+/// or removes pallets or extrinsics reports the pauses it leaves without effect. The
+/// `Executive` runs it before the migrations of the pallets, so it migrates the pauses to `v1`
+/// first.
+pub struct ReconcilePauseEntries<T>(sp_std::marker::PhantomData<T>);
+impl<T: Config> OnRuntimeUpgrade for ReconcilePauseEntries<T> {
+	fn on_runtime_upgrade() -> Weight {
+		v1::migrate::<T>().saturating_add(Pallet::<T>::reconcile_pause_entries())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<(), &'static str> {
+		v1::pre_migrate::<T>()
+	}
+}
+
+/// Migrate the `PausedTransactions` of the unversioned pallet, keyed by `Vec<u8>` names and
+/// holding `()`, to `BoundedVec<u8, MaxNameLen>` names holding a `PauseInfo`.
+pub mod v1 {
+	use super::*;
+
+	const PAUSED_TRANSACTIONS: &[u8] = b"PausedTransactions";
+
+	type OldKey = (Vec<u8>, Vec<u8>);
+
+	fn old_keys<T: Config>() -> impl Iterator<Item = OldKey> {
+		storage_key_iter::<OldKey, (), Twox64Concat>(
+			<Pallet<T> as PalletInfoAccess>::name().as_bytes(),
+			PAUSED_TRANSACTIONS,
+		)
+		.map(|(key, _)| key)
+	}
+
+	fn fits<T: Config>((pallet_name, function_name): &OldKey) -> bool {
+		let max = T::MaxNameLen::get() as usize;
+		pallet_name.len() <= max && function_name.len() <= max
+	}
+
+	/// `BoundedVec` is encoded like `Vec`, so the entries keep their storage keys.
+	/// Entries with a name longer than `MaxNameLen` cannot be decoded any more and are removed.
+	///
+	/// The pauses made before keep an empty reason, are recorded as made in the block of
+	/// the migration and by `PausedBy::Other`, as neither is known, and are not strict.
+	pub fn migrate<T: Config>() -> Weight {
+		if Pallet::<T>::on_chain_storage_version() >= 1 {
+			return T::DbWeight::get().reads(1);
+		}
+
+		let mut reads: Weight = 1;
+		let mut writes: Weight = 1;
+		let too_long = old_keys::<T>()
+			.filter(|key| !fits::<T>(key))
+			.collect::<Vec<_>>();
+		for key in too_long {
+			writes += 1;
+			take_storage_item::<OldKey, (), Twox64Concat>(
+				<Pallet<T> as PalletInfoAccess>::name().as_bytes(),
+				PAUSED_TRANSACTIONS,
+				key,
+			);
+		}
+
+		let info = PauseInfoOf::<T> {
+			reason: Default::default(),
+			paused_at: frame_system::Pallet::<T>::block_number(),
+			paused_by: PausedBy::Other,
+			strict: false,
+		};
+		PausedTransactions::<T>::translate::<(), _>(|_, ()| {
+			reads += 1;
+			writes += 1;
+			Some(info.clone())
+		});
+
+		StorageVersion::new(1).put::<Pallet<T>>();
+		T::DbWeight::get().reads_writes(reads, writes)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	pub fn pre_migrate<T: Config>() -> Result<(), &'static str> {
+		if Pallet::<T>::on_chain_storage_version() >= 1 {
+			return Ok(());
+		}
+		frame_support::ensure!(
+			old_keys::<T>().all(|key| fits::<T>(&key)),
+			"some paused transactions have a name longer than MaxNameLen and will be unpaused"
+		);
+		Ok(())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	pub fn post_migrate<T: Config>() -> Result<(), &'static str> {
+		frame_support::ensure!(
+			Pallet::<T>::on_chain_storage_version() == 1,
+			"storage version of TransactionPause is not 1"
+		);
+		// every remaining entry must decode with bounded names and a `PauseInfo`
+		frame_support::ensure!(
+			PausedTransactions::<T>::iter().count() == old_keys::<T>().count(),
+			"some paused transactions cannot be decoded"
+		);
+		Ok(())
+	}
+}
@@ -22,8 +22,9 @@
 #![cfg(test)]
 
 use super::*;
+use frame_support::traits::EnsureOneOf;
 use frame_support::{construct_runtime, ord_parameter_types, parameter_types};
-use frame_system::EnsureRoot;
+use frame_system::{EnsureRoot, EnsureSignedBy};
 use manta_primitives::Balance;
 
 use sp_core::H256;
@@ -44,6 +45,11 @@ parameter_types! {
 pub struct BaseFilter;
 impl Contains<Call> for BaseFilter {
 	fn contains(call: &Call) -> bool {
+		if tx_pause::EmergencyHaltFilter::<Runtime>::contains(call) {
+			// no halted call
+			return false;
+		}
+
 		if tx_pause::PausedTransactionFilter::<Runtime>::contains(call) {
 			// no paused call
 			return false;
@@ -53,6 +59,13 @@ impl Contains<Call> for BaseFilter {
 	}
 }
 
+pub struct HaltAllowList;
+impl Contains<Call> for HaltAllowList {
+	fn contains(call: &Call) -> bool {
+		matches!(call, Call::System(_) | Call::TransactionPause(_))
+	}
+}
+
 impl frame_system::Config for Runtime {
 	type Origin = Origin;
 	type Index = u64;
@@ -99,11 +112,30 @@ impl pallet_balances::Config for Runtime {
 
 ord_parameter_types! {
 	pub const One: AccountId = 1;
+	pub const Two: AccountId = 2;
+	pub const Three: AccountId = 3;
+}
+
+parameter_types! {
+	pub MaxExpiryWeight: Weight = <() as WeightInfo>::on_initialize(2);
+	pub const MaxNameLen: u32 = 32;
+	pub const MaxReasonLen: u32 = 32;
+	pub const MaxPauseBatch: u32 = 4;
 }
 
 impl Config for Runtime {
 	type Event = Event;
-	type UpdateOrigin = EnsureRoot<AccountId>;
+	type PauseOrigin = EnsureOneOf<EnsureRoot<AccountId>, EnsureSignedBy<Two, AccountId>>;
+	type UnpauseOrigin = EnsureOneOf<EnsureRoot<AccountId>, EnsureSignedBy<Three, AccountId>>;
+	type ForceDispatchOrigin = EnsureRoot<AccountId>;
+	type HaltAllowList = HaltAllowList;
+	type KnownCalls = Call;
+	type NestedCalls = ();
+	type PalletsInfo = AllPalletsWithSystem;
+	type MaxExpiryWeight = MaxExpiryWeight;
+	type MaxNameLen = MaxNameLen;
+	type MaxReasonLen = MaxReasonLen;
+	type MaxPauseBatch = MaxPauseBatch;
 	type WeightInfo = ();
 }
 
@@ -139,3 +171,10 @@ impl ExtBuilder {
 		t.into()
 	}
 }
+
+pub(crate) fn run_to_block(n: u64) {
+	while System::block_number() < n {
+		System::set_block_number(System::block_number() + 1);
+		TransactionPause::on_initialize(System::block_number());
+	}
+}
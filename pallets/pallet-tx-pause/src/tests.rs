@@ -33,6 +33,25 @@ const BALANCE_TRANSFER: &<Runtime as frame_system::Config>::Call =
 		value: 10,
 	});
 
+const REASON: &[u8] = b"incident";
+
+fn info(paused_at: u64, paused_by: PausedBy<AccountId>) -> PauseInfoOf<Runtime> {
+	PauseInfo {
+		reason: REASON.to_vec().try_into().unwrap(),
+		paused_at,
+		paused_by,
+		strict: false,
+	}
+}
+
+fn name(name: &[u8]) -> NameOf<Runtime> {
+	name.to_vec().try_into().unwrap()
+}
+
+fn key(pallet_name: &[u8], function_name: &[u8]) -> (NameOf<Runtime>, NameOf<Runtime>) {
+	(name(pallet_name), name(function_name))
+}
+
 #[test]
 fn pause_transaction_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -45,27 +64,33 @@ fn pause_transaction_work() {
 			TransactionPause::pause_transaction(
 				Origin::signed(1),
 				b"Balances".to_vec(),
-				b"transfer".to_vec()
+				b"transfer".to_vec(),
+				None,
+				REASON.to_vec()
 			),
 			BadOrigin
 		);
 
 		assert_eq!(
-			TransactionPause::paused_transactions((b"Balances".to_vec(), b"transfer".to_vec())),
+			TransactionPause::paused_transactions(key(b"Balances", b"transfer")),
 			None
 		);
 		assert_ok!(TransactionPause::pause_transaction(
 			RawOrigin::Root.into(),
 			b"Balances".to_vec(),
-			b"transfer".to_vec()
+			b"transfer".to_vec(),
+			None,
+			REASON.to_vec()
 		));
 		System::assert_last_event(Event::TransactionPause(crate::Event::TransactionPaused(
 			b"Balances".to_vec(),
 			b"transfer".to_vec(),
+			None,
+			info(1, PausedBy::Root),
 		)));
 		assert_eq!(
-			TransactionPause::paused_transactions((b"Balances".to_vec(), b"transfer".to_vec())),
-			Some(())
+			TransactionPause::paused_transactions(key(b"Balances", b"transfer")),
+			Some(info(1, PausedBy::Root))
 		);
 		assert!(!<Runtime as frame_system::Config>::BaseCallFilter::contains(BALANCE_TRANSFER));
 
@@ -73,7 +98,9 @@ fn pause_transaction_work() {
 			TransactionPause::pause_transaction(
 				RawOrigin::Root.into(),
 				b"TransactionPause".to_vec(),
-				b"pause_transaction".to_vec()
+				b"pause_transaction".to_vec(),
+				None,
+				REASON.to_vec()
 			),
 			Error::<Runtime>::CannotPause
 		);
@@ -81,14 +108,71 @@ fn pause_transaction_work() {
 			TransactionPause::pause_transaction(
 				RawOrigin::Root.into(),
 				b"TransactionPause".to_vec(),
-				b"some_other_call".to_vec()
+				b"some_other_call".to_vec(),
+				None,
+				REASON.to_vec()
 			),
 			Error::<Runtime>::CannotPause
 		);
-		assert_ok!(TransactionPause::pause_transaction(
+		assert_noop!(
+			TransactionPause::pause_transaction(
+				RawOrigin::Root.into(),
+				b"OtherPallet".to_vec(),
+				b"pause_transaction".to_vec(),
+				None,
+				REASON.to_vec()
+			),
+			Error::<Runtime>::UnknownCall
+		);
+	});
+}
+
+#[test]
+fn unknown_calls_are_rejected() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_noop!(
+			TransactionPause::pause_transaction(
+				RawOrigin::Root.into(),
+				b"Balances".to_vec(),
+				b"transfr".to_vec(),
+				None,
+				REASON.to_vec()
+			),
+			Error::<Runtime>::UnknownCall
+		);
+		assert_noop!(
+			TransactionPause::pause_transaction(
+				RawOrigin::Root.into(),
+				b"Balance".to_vec(),
+				b"transfer".to_vec(),
+				None,
+				REASON.to_vec()
+			),
+			Error::<Runtime>::UnknownCall
+		);
+		assert_noop!(
+			TransactionPause::pause_pallet(RawOrigin::Root.into(), b"Balance".to_vec()),
+			Error::<Runtime>::UnknownCall
+		);
+		assert_noop!(
+			TransactionPause::pause_transactions(
+				RawOrigin::Root.into(),
+				vec![
+					(b"Balances".to_vec(), b"transfer".to_vec()),
+					(b"Balances".to_vec(), b"transfr".to_vec()),
+				],
+				REASON.to_vec()
+			),
+			Error::<Runtime>::UnknownCall
+		);
+
+		// Unknown names can still be unpaused, e.g. after a runtime upgrade removed a call.
+		assert_ok!(TransactionPause::unpause_transaction(
 			RawOrigin::Root.into(),
-			b"OtherPallet".to_vec(),
-			b"pause_transaction".to_vec()
+			b"Balances".to_vec(),
+			b"transfr".to_vec()
 		));
 	});
 }
@@ -105,11 +189,13 @@ fn unpause_transaction_work() {
 		assert_ok!(TransactionPause::pause_transaction(
 			RawOrigin::Root.into(),
 			b"Balances".to_vec(),
-			b"transfer".to_vec()
+			b"transfer".to_vec(),
+			None,
+			REASON.to_vec()
 		));
 		assert_eq!(
-			TransactionPause::paused_transactions((b"Balances".to_vec(), b"transfer".to_vec())),
-			Some(())
+			TransactionPause::paused_transactions(key(b"Balances", b"transfer")),
+			Some(info(1, PausedBy::Root))
 		);
 
 		assert!(!<Runtime as frame_system::Config>::BaseCallFilter::contains(BALANCE_TRANSFER));
@@ -131,9 +217,10 @@ fn unpause_transaction_work() {
 		System::assert_last_event(Event::TransactionPause(crate::Event::TransactionUnpaused(
 			b"Balances".to_vec(),
 			b"transfer".to_vec(),
+			false,
 		)));
 		assert_eq!(
-			TransactionPause::paused_transactions((b"Balances".to_vec(), b"transfer".to_vec())),
+			TransactionPause::paused_transactions(key(b"Balances", b"transfer")),
 			None
 		);
 
@@ -152,7 +239,9 @@ fn paused_transaction_filter_work() {
 		assert_ok!(TransactionPause::pause_transaction(
 			RawOrigin::Root.into(),
 			b"Balances".to_vec(),
-			b"transfer".to_vec()
+			b"transfer".to_vec(),
+			None,
+			REASON.to_vec()
 		));
 		assert!(PausedTransactionFilter::<Runtime>::contains(
 			BALANCE_TRANSFER
@@ -167,3 +256,1365 @@ fn paused_transaction_filter_work() {
 		));
 	});
 }
+
+#[test]
+fn pause_pallet_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert!(<Runtime as frame_system::Config>::BaseCallFilter::contains(
+			BALANCE_TRANSFER
+		));
+
+		System::set_block_number(1);
+		assert_noop!(
+			TransactionPause::pause_pallet(Origin::signed(1), b"Balances".to_vec()),
+			BadOrigin
+		);
+
+		assert_eq!(TransactionPause::paused_pallets(name(b"Balances")), None);
+		assert_ok!(TransactionPause::pause_pallet(
+			RawOrigin::Root.into(),
+			b"Balances".to_vec()
+		));
+		System::assert_last_event(Event::TransactionPause(crate::Event::PalletPaused(
+			b"Balances".to_vec(),
+		)));
+		assert_eq!(
+			TransactionPause::paused_pallets(name(b"Balances")),
+			Some(())
+		);
+		assert!(!<Runtime as frame_system::Config>::BaseCallFilter::contains(BALANCE_TRANSFER));
+		assert!(
+			!<Runtime as frame_system::Config>::BaseCallFilter::contains(&mock::Call::Balances(
+				pallet_balances::Call::transfer_all {
+					dest: ALICE,
+					keep_alive: true,
+				}
+			))
+		);
+
+		assert_noop!(
+			TransactionPause::pause_pallet(RawOrigin::Root.into(), b"TransactionPause".to_vec()),
+			Error::<Runtime>::CannotPause
+		);
+		for core_pallet in CORE_PALLETS {
+			assert_noop!(
+				TransactionPause::pause_pallet(
+					RawOrigin::Root.into(),
+					core_pallet.as_bytes().to_vec()
+				),
+				Error::<Runtime>::CannotPause
+			);
+			assert_noop!(
+				TransactionPause::pause_transaction(
+					RawOrigin::Root.into(),
+					core_pallet.as_bytes().to_vec(),
+					b"remark".to_vec(),
+					None,
+					REASON.to_vec()
+				),
+				Error::<Runtime>::CannotPause
+			);
+		}
+	});
+}
+
+#[test]
+fn unpause_pallet_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(TransactionPause::pause_pallet(
+			RawOrigin::Root.into(),
+			b"Balances".to_vec()
+		));
+		assert_ok!(TransactionPause::pause_transaction(
+			RawOrigin::Root.into(),
+			b"Balances".to_vec(),
+			b"transfer".to_vec(),
+			None,
+			REASON.to_vec()
+		));
+		assert!(!<Runtime as frame_system::Config>::BaseCallFilter::contains(BALANCE_TRANSFER));
+
+		assert_noop!(
+			TransactionPause::unpause_pallet(Origin::signed(1), b"Balances".to_vec()),
+			BadOrigin
+		);
+
+		assert_ok!(TransactionPause::unpause_pallet(
+			RawOrigin::Root.into(),
+			b"Balances".to_vec()
+		));
+		System::assert_last_event(Event::TransactionPause(crate::Event::PalletUnpaused(
+			b"Balances".to_vec(),
+		)));
+		assert_eq!(TransactionPause::paused_pallets(name(b"Balances")), None);
+
+		// The single paused call is still paused.
+		assert!(!<Runtime as frame_system::Config>::BaseCallFilter::contains(BALANCE_TRANSFER));
+		assert_ok!(TransactionPause::unpause_transaction(
+			RawOrigin::Root.into(),
+			b"Balances".to_vec(),
+			b"transfer".to_vec()
+		));
+		assert!(<Runtime as frame_system::Config>::BaseCallFilter::contains(
+			BALANCE_TRANSFER
+		));
+	});
+}
+
+#[test]
+fn pause_transaction_with_expiry_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_noop!(
+			TransactionPause::pause_transaction(
+				RawOrigin::Root.into(),
+				b"Balances".to_vec(),
+				b"transfer".to_vec(),
+				Some(1),
+				REASON.to_vec()
+			),
+			Error::<Runtime>::InvalidExpiry
+		);
+
+		assert_ok!(TransactionPause::pause_transaction(
+			RawOrigin::Root.into(),
+			b"Balances".to_vec(),
+			b"transfer".to_vec(),
+			Some(5),
+			REASON.to_vec()
+		));
+		System::assert_last_event(Event::TransactionPause(crate::Event::TransactionPaused(
+			b"Balances".to_vec(),
+			b"transfer".to_vec(),
+			Some(5),
+			info(1, PausedBy::Root),
+		)));
+		assert_eq!(
+			TransactionPause::pause_expiry(key(b"Balances", b"transfer")),
+			Some(5)
+		);
+		assert_eq!(
+			TransactionPause::remaining_pause_duration(b"Balances", b"transfer"),
+			Some(4)
+		);
+		assert!(!<Runtime as frame_system::Config>::BaseCallFilter::contains(BALANCE_TRANSFER));
+
+		run_to_block(4);
+		assert_eq!(
+			TransactionPause::remaining_pause_duration(b"Balances", b"transfer"),
+			Some(1)
+		);
+		assert!(!<Runtime as frame_system::Config>::BaseCallFilter::contains(BALANCE_TRANSFER));
+
+		run_to_block(5);
+		System::assert_last_event(Event::TransactionPause(crate::Event::TransactionUnpaused(
+			b"Balances".to_vec(),
+			b"transfer".to_vec(),
+			true,
+		)));
+		assert_eq!(
+			TransactionPause::paused_transactions(key(b"Balances", b"transfer")),
+			None
+		);
+		assert_eq!(
+			TransactionPause::remaining_pause_duration(b"Balances", b"transfer"),
+			None
+		);
+		assert!(<Runtime as frame_system::Config>::BaseCallFilter::contains(
+			BALANCE_TRANSFER
+		));
+	});
+}
+
+#[test]
+fn rescheduled_or_unpaused_expiry_is_ignored() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		// Extend the pause beyond its first expiry.
+		assert_ok!(TransactionPause::pause_transaction(
+			RawOrigin::Root.into(),
+			b"Balances".to_vec(),
+			b"transfer".to_vec(),
+			Some(3),
+			REASON.to_vec()
+		));
+		assert_ok!(TransactionPause::pause_transaction(
+			RawOrigin::Root.into(),
+			b"Balances".to_vec(),
+			b"transfer".to_vec(),
+			Some(6),
+			REASON.to_vec()
+		));
+		run_to_block(3);
+		assert!(!<Runtime as frame_system::Config>::BaseCallFilter::contains(BALANCE_TRANSFER));
+		run_to_block(6);
+		assert!(<Runtime as frame_system::Config>::BaseCallFilter::contains(
+			BALANCE_TRANSFER
+		));
+
+		// Make a time-limited pause indefinite.
+		assert_ok!(TransactionPause::pause_transaction(
+			RawOrigin::Root.into(),
+			b"Balances".to_vec(),
+			b"transfer".to_vec(),
+			Some(8),
+			REASON.to_vec()
+		));
+		assert_ok!(TransactionPause::pause_transaction(
+			RawOrigin::Root.into(),
+			b"Balances".to_vec(),
+			b"transfer".to_vec(),
+			None,
+			REASON.to_vec()
+		));
+		run_to_block(10);
+		assert!(!<Runtime as frame_system::Config>::BaseCallFilter::contains(BALANCE_TRANSFER));
+
+		// A manual unpause drops the expiry.
+		assert_ok!(TransactionPause::pause_transaction(
+			RawOrigin::Root.into(),
+			b"Balances".to_vec(),
+			b"transfer".to_vec(),
+			Some(12),
+			REASON.to_vec()
+		));
+		assert_ok!(TransactionPause::unpause_transaction(
+			RawOrigin::Root.into(),
+			b"Balances".to_vec(),
+			b"transfer".to_vec()
+		));
+		assert_eq!(
+			TransactionPause::pause_expiry(key(b"Balances", b"transfer")),
+			None
+		);
+		run_to_block(12);
+		System::assert_last_event(Event::TransactionPause(crate::Event::TransactionUnpaused(
+			b"Balances".to_vec(),
+			b"transfer".to_vec(),
+			false,
+		)));
+	});
+}
+
+#[test]
+fn expired_pauses_over_weight_limit_are_carried_over() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		let function_names: Vec<Vec<u8>> = vec![
+			b"transfer".to_vec(),
+			b"transfer_keep_alive".to_vec(),
+			b"transfer_all".to_vec(),
+		];
+		for function_name in function_names.iter() {
+			assert_ok!(TransactionPause::pause_transaction(
+				RawOrigin::Root.into(),
+				b"Balances".to_vec(),
+				function_name.clone(),
+				Some(3),
+				REASON.to_vec()
+			));
+		}
+
+		// `MaxExpiryWeight` only allows two expired pauses per block.
+		run_to_block(3);
+		assert_eq!(
+			function_names
+				.iter()
+				.filter(|f| TransactionPause::paused_transactions(key(b"Balances", f)).is_some())
+				.count(),
+			1
+		);
+		assert_eq!(PauseExpiryQueue::<Runtime>::iter_prefix(3).count(), 1);
+		assert_eq!(ExpiryCursor::<Runtime>::get(), Some(3));
+
+		run_to_block(4);
+		assert!(function_names
+			.iter()
+			.all(|f| TransactionPause::paused_transactions(key(b"Balances", f)).is_none()));
+		assert_eq!(PauseExpiryQueue::<Runtime>::iter_prefix(3).count(), 0);
+		assert_eq!(ExpiryCursor::<Runtime>::get(), None);
+	});
+}
+
+#[test]
+fn expiry_cursor_is_bounded_by_the_weight_limit() {
+	use frame_support::traits::Hooks;
+
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(TransactionPause::pause_transaction(
+			RawOrigin::Root.into(),
+			b"Balances".to_vec(),
+			b"transfer".to_vec(),
+			Some(2),
+			REASON.to_vec()
+		));
+
+		// The pause is lifted late, when `on_initialize` did not run at its expiry block.
+		ExpiryCursor::<Runtime>::put(2);
+		System::set_block_number(10);
+		let weight = TransactionPause::on_initialize(10);
+		assert!(weight <= MaxExpiryWeight::get());
+		assert_eq!(weight, <() as WeightInfo>::on_initialize(1));
+		assert!(TransactionPause::paused_transactions(key(b"Balances", b"transfer")).is_none());
+		assert_eq!(ExpiryCursor::<Runtime>::get(), None);
+	});
+}
+
+#[test]
+fn pause_transactions_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		let calls = vec![
+			(b"Balances".to_vec(), b"transfer".to_vec()),
+			(b"Balances".to_vec(), b"transfer_all".to_vec()),
+		];
+		assert_noop!(
+			TransactionPause::pause_transactions(Origin::signed(1), calls.clone(), REASON.to_vec()),
+			BadOrigin
+		);
+
+		assert_ok!(TransactionPause::pause_transactions(
+			RawOrigin::Root.into(),
+			calls.clone(),
+			REASON.to_vec()
+		));
+		System::assert_has_event(Event::TransactionPause(crate::Event::TransactionPaused(
+			b"Balances".to_vec(),
+			b"transfer".to_vec(),
+			None,
+			info(1, PausedBy::Root),
+		)));
+		System::assert_last_event(Event::TransactionPause(crate::Event::TransactionPaused(
+			b"Balances".to_vec(),
+			b"transfer_all".to_vec(),
+			None,
+			info(1, PausedBy::Root),
+		)));
+		assert!(!<Runtime as frame_system::Config>::BaseCallFilter::contains(BALANCE_TRANSFER));
+		assert!(
+			!<Runtime as frame_system::Config>::BaseCallFilter::contains(&mock::Call::Balances(
+				pallet_balances::Call::transfer_all {
+					dest: ALICE,
+					keep_alive: true,
+				}
+			))
+		);
+
+		assert_ok!(TransactionPause::unpause_transactions(
+			RawOrigin::Root.into(),
+			calls
+		));
+		System::assert_last_event(Event::TransactionPause(crate::Event::TransactionUnpaused(
+			b"Balances".to_vec(),
+			b"transfer_all".to_vec(),
+			false,
+		)));
+		assert!(<Runtime as frame_system::Config>::BaseCallFilter::contains(
+			BALANCE_TRANSFER
+		));
+		assert_eq!(PausedTransactions::<Runtime>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn pause_transactions_is_all_or_nothing() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		let too_many = vec![(b"Balances".to_vec(), b"transfer".to_vec()); 5];
+		assert_noop!(
+			TransactionPause::pause_transactions(
+				RawOrigin::Root.into(),
+				too_many.clone(),
+				REASON.to_vec()
+			),
+			Error::<Runtime>::TooManyCalls
+		);
+		assert_noop!(
+			TransactionPause::unpause_transactions(RawOrigin::Root.into(), too_many),
+			Error::<Runtime>::TooManyCalls
+		);
+
+		assert_noop!(
+			TransactionPause::pause_transactions(
+				RawOrigin::Root.into(),
+				vec![
+					(b"Balances".to_vec(), b"transfer".to_vec()),
+					(b"System".to_vec(), b"remark".to_vec()),
+				],
+				REASON.to_vec()
+			),
+			Error::<Runtime>::CannotPause
+		);
+		assert_eq!(
+			TransactionPause::paused_transactions(key(b"Balances", b"transfer")),
+			None
+		);
+	});
+}
+
+#[test]
+fn names_longer_than_max_name_len_are_rejected() {
+	ExtBuilder::default().build().execute_with(|| {
+		let long_name = vec![b'a'; 33];
+		assert_noop!(
+			TransactionPause::pause_transaction(
+				RawOrigin::Root.into(),
+				b"Balances".to_vec(),
+				long_name.clone(),
+				None,
+				REASON.to_vec()
+			),
+			Error::<Runtime>::NameTooLong
+		);
+		assert_noop!(
+			TransactionPause::pause_pallet(RawOrigin::Root.into(), long_name.clone()),
+			Error::<Runtime>::NameTooLong
+		);
+		assert_noop!(
+			TransactionPause::pause_transactions(
+				RawOrigin::Root.into(),
+				vec![(long_name, b"transfer".to_vec())],
+				REASON.to_vec()
+			),
+			Error::<Runtime>::NameTooLong
+		);
+	});
+}
+
+#[test]
+fn migrate_to_v1_work() {
+	use frame_support::{
+		storage::migration::put_storage_value, traits::GetStorageVersion, StorageHasher,
+	};
+
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(5);
+		StorageVersion::new(0).put::<TransactionPause>();
+
+		let put_old = |pallet_name: &[u8], function_name: &[u8]| {
+			let hashed_key =
+				(pallet_name.to_vec(), function_name.to_vec()).using_encoded(Twox64Concat::hash);
+			put_storage_value(b"TransactionPause", b"PausedTransactions", &hashed_key, ());
+		};
+		put_old(b"Balances", b"transfer");
+		put_old(b"Balances", &[b'a'; 33]);
+
+		migrations::v1::migrate::<Runtime>();
+
+		assert_eq!(TransactionPause::on_chain_storage_version(), 1);
+		assert_eq!(
+			PausedTransactions::<Runtime>::iter().collect::<Vec<_>>(),
+			vec![(
+				key(b"Balances", b"transfer"),
+				PauseInfo {
+					reason: Default::default(),
+					paused_at: 5,
+					paused_by: PausedBy::Other,
+					strict: false,
+				}
+			)]
+		);
+		assert!(!<Runtime as frame_system::Config>::BaseCallFilter::contains(BALANCE_TRANSFER));
+		assert!(!TransactionPause::is_strictly_paused(BALANCE_TRANSFER));
+
+		// a second run changes nothing
+		System::set_block_number(6);
+		migrations::v1::migrate::<Runtime>();
+		assert_eq!(
+			TransactionPause::paused_transactions(key(b"Balances", b"transfer"))
+				.map(|info| info.paused_at),
+			Some(5)
+		);
+	});
+}
+
+#[test]
+fn pause_and_unpause_origins_are_separate() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		// `Two` may pause but not unpause.
+		assert_ok!(TransactionPause::pause_transaction(
+			Origin::signed(2),
+			b"Balances".to_vec(),
+			b"transfer".to_vec(),
+			None,
+			REASON.to_vec()
+		));
+		assert_ok!(TransactionPause::pause_pallet(
+			Origin::signed(2),
+			b"Balances".to_vec()
+		));
+		assert_noop!(
+			TransactionPause::unpause_transaction(
+				Origin::signed(2),
+				b"Balances".to_vec(),
+				b"transfer".to_vec()
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			TransactionPause::unpause_pallet(Origin::signed(2), b"Balances".to_vec()),
+			BadOrigin
+		);
+		assert_noop!(
+			TransactionPause::unpause_transactions(
+				Origin::signed(2),
+				vec![(b"Balances".to_vec(), b"transfer".to_vec())]
+			),
+			BadOrigin
+		);
+
+		assert_ok!(TransactionPause::unpause_pallet(
+			RawOrigin::Root.into(),
+			b"Balances".to_vec()
+		));
+		assert_ok!(TransactionPause::unpause_transaction(
+			RawOrigin::Root.into(),
+			b"Balances".to_vec(),
+			b"transfer".to_vec()
+		));
+		assert!(<Runtime as frame_system::Config>::BaseCallFilter::contains(
+			BALANCE_TRANSFER
+		));
+	});
+}
+
+#[test]
+fn emergency_halt_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_noop!(TransactionPause::halt(Origin::signed(1)), BadOrigin);
+		assert_ok!(TransactionPause::halt(Origin::signed(2)));
+		System::assert_last_event(Event::TransactionPause(crate::Event::EmergencyHalted));
+		assert!(TransactionPause::emergency_halt());
+
+		assert!(!<Runtime as frame_system::Config>::BaseCallFilter::contains(BALANCE_TRANSFER));
+		// allow-listed calls are not halted
+		assert!(<Runtime as frame_system::Config>::BaseCallFilter::contains(
+			&mock::Call::System(frame_system::Call::remark { remark: vec![] })
+		));
+		assert!(<Runtime as frame_system::Config>::BaseCallFilter::contains(
+			&mock::Call::TransactionPause(crate::Call::resume {})
+		));
+
+		assert_noop!(TransactionPause::resume(Origin::signed(2)), BadOrigin);
+		assert_ok!(TransactionPause::resume(RawOrigin::Root.into()));
+		System::assert_last_event(Event::TransactionPause(crate::Event::EmergencyResumed));
+		assert!(!TransactionPause::emergency_halt());
+		assert!(<Runtime as frame_system::Config>::BaseCallFilter::contains(
+			BALANCE_TRANSFER
+		));
+	});
+}
+
+#[test]
+fn resume_keeps_paused_transactions() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(TransactionPause::pause_transaction(
+			RawOrigin::Root.into(),
+			b"Balances".to_vec(),
+			b"transfer".to_vec(),
+			None,
+			REASON.to_vec()
+		));
+		assert_ok!(TransactionPause::halt(RawOrigin::Root.into()));
+		assert_ok!(TransactionPause::resume(RawOrigin::Root.into()));
+		assert!(!<Runtime as frame_system::Config>::BaseCallFilter::contains(BALANCE_TRANSFER));
+
+		// resuming a running chain is a no-op
+		assert_ok!(TransactionPause::resume(RawOrigin::Root.into()));
+		System::assert_last_event(Event::TransactionPause(crate::Event::EmergencyResumed));
+		assert_eq!(
+			System::events()
+				.iter()
+				.filter(|record| record.event
+					== Event::TransactionPause(crate::Event::EmergencyResumed))
+				.count(),
+			1
+		);
+	});
+}
+
+#[test]
+fn pause_status_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_eq!(TransactionPause::pause_status(BALANCE_TRANSFER), None);
+		assert_eq!(TransactionPause::throttle_status(BALANCE_TRANSFER), None);
+		assert!(TransactionPause::pause_entries().is_empty());
+
+		assert_ok!(TransactionPause::throttle_transaction(
+			RawOrigin::Root.into(),
+			b"Balances".to_vec(),
+			b"transfer".to_vec(),
+			2
+		));
+		assert_eq!(
+			TransactionPause::throttle_status(BALANCE_TRANSFER),
+			Some(CallFilterStatus::Throttled)
+		);
+		assert_eq!(
+			TransactionPause::pause_entries(),
+			vec![(
+				b"Balances".to_vec(),
+				b"transfer".to_vec(),
+				PauseStatus::Throttled { max_per_block: 2 }
+			)]
+		);
+		assert_ok!(TransactionPause::unthrottle_transaction(
+			RawOrigin::Root.into(),
+			b"Balances".to_vec(),
+			b"transfer".to_vec()
+		));
+
+		assert_ok!(TransactionPause::pause_transaction(
+			RawOrigin::Root.into(),
+			b"Balances".to_vec(),
+			b"transfer".to_vec(),
+			None,
+			REASON.to_vec()
+		));
+		assert_eq!(
+			TransactionPause::pause_status(BALANCE_TRANSFER),
+			Some(CallFilterStatus::TransactionPaused)
+		);
+		assert_eq!(
+			TransactionPause::pause_entries(),
+			vec![(
+				b"Balances".to_vec(),
+				b"transfer".to_vec(),
+				PauseStatus::TransactionPaused { strict: false }
+			)]
+		);
+
+		assert_ok!(TransactionPause::pause_pallet(
+			RawOrigin::Root.into(),
+			b"Balances".to_vec()
+		));
+		assert_eq!(
+			TransactionPause::pause_status(BALANCE_TRANSFER),
+			Some(CallFilterStatus::PalletPaused)
+		);
+
+		// a call paused by its index is listed with its names
+		assert_ok!(TransactionPause::pause_call(
+			RawOrigin::Root.into(),
+			Box::new(mock::Call::Balances(pallet_balances::Call::transfer_all {
+				dest: 42,
+				keep_alive: false,
+			})),
+			REASON.to_vec()
+		));
+		let (pallet_index, call_index) = TransactionPause::call_index(&mock::Call::Balances(
+			pallet_balances::Call::transfer_all {
+				dest: 42,
+				keep_alive: false,
+			},
+		))
+		.unwrap();
+		assert_eq!(
+			TransactionPause::pause_entries(),
+			vec![
+				(b"Balances".to_vec(), vec![], PauseStatus::PalletPaused),
+				(
+					b"Balances".to_vec(),
+					b"transfer".to_vec(),
+					PauseStatus::TransactionPaused { strict: false }
+				),
+				(
+					b"Balances".to_vec(),
+					b"transfer_all".to_vec(),
+					PauseStatus::CallIndexPaused {
+						pallet_index,
+						call_index
+					}
+				),
+			]
+		);
+
+		assert_ok!(TransactionPause::halt(RawOrigin::Root.into()));
+		assert_eq!(
+			TransactionPause::pause_status(BALANCE_TRANSFER),
+			Some(CallFilterStatus::Halted)
+		);
+	});
+}
+
+#[test]
+fn check_tx_pause_rejects_filtered_calls() {
+	use frame_support::weights::DispatchInfo;
+	use sp_runtime::{
+		traits::SignedExtension,
+		transaction_validity::{InvalidTransaction, TransactionValidityError},
+	};
+
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let info = DispatchInfo::default();
+
+		assert_ok!(CheckTxPause::<Runtime>::new().validate(&ALICE, BALANCE_TRANSFER, &info, 0));
+		assert_ok!(CheckTxPause::<Runtime>::new().pre_dispatch(&ALICE, BALANCE_TRANSFER, &info, 0));
+
+		assert_ok!(TransactionPause::pause_transaction(
+			RawOrigin::Root.into(),
+			b"Balances".to_vec(),
+			b"transfer".to_vec(),
+			None,
+			REASON.to_vec()
+		));
+		assert_eq!(
+			CheckTxPause::<Runtime>::new().validate(&ALICE, BALANCE_TRANSFER, &info, 0),
+			Err(TransactionValidityError::Invalid(InvalidTransaction::Call))
+		);
+		assert_eq!(
+			CheckTxPause::<Runtime>::new().pre_dispatch(&ALICE, BALANCE_TRANSFER, &info, 0),
+			Err(TransactionValidityError::Invalid(InvalidTransaction::Call))
+		);
+
+		// calls not paused on their own are rejected during an emergency halt
+		let transfer_all = mock::Call::Balances(pallet_balances::Call::transfer_all {
+			dest: ALICE,
+			keep_alive: true,
+		});
+		assert_ok!(CheckTxPause::<Runtime>::new().validate(&ALICE, &transfer_all, &info, 0));
+		assert_ok!(TransactionPause::halt(RawOrigin::Root.into()));
+		assert_eq!(
+			CheckTxPause::<Runtime>::new().validate(&ALICE, &transfer_all, &info, 0),
+			Err(TransactionValidityError::Invalid(InvalidTransaction::Call))
+		);
+	});
+}
+
+#[test]
+fn pause_info_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_noop!(
+			TransactionPause::pause_transaction(
+				RawOrigin::Root.into(),
+				b"Balances".to_vec(),
+				b"transfer".to_vec(),
+				None,
+				vec![b'a'; 33]
+			),
+			Error::<Runtime>::ReasonTooLong
+		);
+
+		assert_ok!(TransactionPause::pause_transaction(
+			Origin::signed(2),
+			b"Balances".to_vec(),
+			b"transfer".to_vec(),
+			None,
+			REASON.to_vec()
+		));
+		assert_eq!(
+			TransactionPause::paused_transactions(key(b"Balances", b"transfer")),
+			Some(info(1, PausedBy::Signed(2)))
+		);
+
+		// Pausing again with another reason records the new pause.
+		System::set_block_number(2);
+		assert_ok!(TransactionPause::pause_transaction(
+			RawOrigin::Root.into(),
+			b"Balances".to_vec(),
+			b"transfer".to_vec(),
+			None,
+			b"another incident".to_vec()
+		));
+		let new_info = PauseInfo {
+			reason: b"another incident".to_vec().try_into().unwrap(),
+			paused_at: 2,
+			paused_by: PausedBy::Root,
+			strict: false,
+		};
+		System::assert_last_event(Event::TransactionPause(crate::Event::TransactionPaused(
+			b"Balances".to_vec(),
+			b"transfer".to_vec(),
+			None,
+			new_info.clone(),
+		)));
+		assert_eq!(
+			TransactionPause::paused_transactions(key(b"Balances", b"transfer")),
+			Some(new_info)
+		);
+	});
+}
+
+#[test]
+fn throttle_transaction_work() {
+	use frame_support::weights::DispatchInfo;
+	use sp_runtime::{
+		traits::SignedExtension,
+		transaction_validity::{InvalidTransaction, TransactionValidityError},
+	};
+
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let info = DispatchInfo::default();
+		let pre_dispatch =
+			|| CheckTxPause::<Runtime>::new().pre_dispatch(&ALICE, BALANCE_TRANSFER, &info, 0);
+
+		assert_noop!(
+			TransactionPause::throttle_transaction(
+				Origin::signed(1),
+				b"Balances".to_vec(),
+				b"transfer".to_vec(),
+				2
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			TransactionPause::throttle_transaction(
+				RawOrigin::Root.into(),
+				b"Balances".to_vec(),
+				b"transfer".to_vec(),
+				0
+			),
+			Error::<Runtime>::InvalidThrottle
+		);
+		assert_noop!(
+			TransactionPause::throttle_transaction(
+				RawOrigin::Root.into(),
+				b"Balances".to_vec(),
+				b"transfr".to_vec(),
+				2
+			),
+			Error::<Runtime>::UnknownCall
+		);
+
+		assert_ok!(TransactionPause::throttle_transaction(
+			Origin::signed(2),
+			b"Balances".to_vec(),
+			b"transfer".to_vec(),
+			2
+		));
+		System::assert_last_event(Event::TransactionPause(crate::Event::TransactionThrottled(
+			b"Balances".to_vec(),
+			b"transfer".to_vec(),
+			2,
+		)));
+		assert_eq!(
+			TransactionPause::throttled_transactions(key(b"Balances", b"transfer")),
+			Some(2)
+		);
+		// a throttled call is not filtered
+		assert!(<Runtime as frame_system::Config>::BaseCallFilter::contains(
+			BALANCE_TRANSFER
+		));
+
+		assert_ok!(pre_dispatch());
+		assert_ok!(pre_dispatch());
+		assert_eq!(
+			pre_dispatch(),
+			Err(TransactionValidityError::Invalid(
+				InvalidTransaction::ExhaustsResources
+			))
+		);
+		// the pool does not take more calls in this block either
+		assert_eq!(
+			CheckTxPause::<Runtime>::new().validate(&ALICE, BALANCE_TRANSFER, &info, 0),
+			Err(TransactionValidityError::Invalid(
+				InvalidTransaction::ExhaustsResources
+			))
+		);
+		// other calls are not counted
+		assert_ok!(CheckTxPause::<Runtime>::new().pre_dispatch(
+			&ALICE,
+			&mock::Call::Balances(pallet_balances::Call::transfer_all {
+				dest: ALICE,
+				keep_alive: true,
+			}),
+			&info,
+			0
+		));
+
+		// the count restarts in the next block, without any reset in `on_initialize`
+		System::set_block_number(2);
+		assert_eq!(
+			TransactionPause::throttle_count(&key(b"Balances", b"transfer")),
+			0
+		);
+		assert_ok!(CheckTxPause::<Runtime>::new().validate(&ALICE, BALANCE_TRANSFER, &info, 0));
+		assert_ok!(pre_dispatch());
+		assert_ok!(pre_dispatch());
+		assert!(pre_dispatch().is_err());
+
+		assert_noop!(
+			TransactionPause::unthrottle_transaction(
+				Origin::signed(2),
+				b"Balances".to_vec(),
+				b"transfer".to_vec()
+			),
+			BadOrigin
+		);
+		assert_ok!(TransactionPause::unthrottle_transaction(
+			RawOrigin::Root.into(),
+			b"Balances".to_vec(),
+			b"transfer".to_vec()
+		));
+		System::assert_last_event(Event::TransactionPause(
+			crate::Event::TransactionUnthrottled(b"Balances".to_vec(), b"transfer".to_vec()),
+		));
+		assert_ok!(pre_dispatch());
+	});
+}
+
+#[test]
+fn paused_throttled_transaction_is_rejected() {
+	use frame_support::weights::DispatchInfo;
+	use sp_runtime::{
+		traits::SignedExtension,
+		transaction_validity::{InvalidTransaction, TransactionValidityError},
+	};
+
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let info = DispatchInfo::default();
+
+		assert_ok!(TransactionPause::throttle_transaction(
+			RawOrigin::Root.into(),
+			b"Balances".to_vec(),
+			b"transfer".to_vec(),
+			2
+		));
+		assert_ok!(TransactionPause::pause_transaction(
+			RawOrigin::Root.into(),
+			b"Balances".to_vec(),
+			b"transfer".to_vec(),
+			None,
+			REASON.to_vec()
+		));
+		assert_eq!(
+			CheckTxPause::<Runtime>::new().pre_dispatch(&ALICE, BALANCE_TRANSFER, &info, 0),
+			Err(TransactionValidityError::Invalid(InvalidTransaction::Call))
+		);
+		// a rejected call is not counted
+		assert_eq!(
+			TransactionPause::throttle_count(&key(b"Balances", b"transfer")),
+			0
+		);
+	});
+}
+
+#[test]
+fn pause_call_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		let (pallet_index, call_index) = TransactionPause::call_index(BALANCE_TRANSFER).unwrap();
+		assert_eq!((pallet_index, call_index), (2, 0));
+		// the indices from the metadata are those of the encoding
+		let transfer_all = mock::Call::Balances(pallet_balances::Call::transfer_all {
+			dest: ALICE,
+			keep_alive: true,
+		});
+		let encoded = transfer_all.encode();
+		assert_eq!(
+			TransactionPause::call_index(&transfer_all),
+			Some((encoded[0], encoded[1]))
+		);
+
+		assert_noop!(
+			TransactionPause::pause_call(
+				Origin::signed(1),
+				Box::new(BALANCE_TRANSFER.clone()),
+				REASON.to_vec()
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			TransactionPause::pause_call(
+				RawOrigin::Root.into(),
+				Box::new(mock::Call::System(frame_system::Call::remark {
+					remark: vec![]
+				})),
+				REASON.to_vec()
+			),
+			Error::<Runtime>::CannotPause
+		);
+		assert_noop!(
+			TransactionPause::pause_call(
+				RawOrigin::Root.into(),
+				Box::new(mock::Call::TransactionPause(crate::Call::resume {})),
+				REASON.to_vec()
+			),
+			Error::<Runtime>::CannotPause
+		);
+
+		// The arguments of the call do not matter.
+		assert_ok!(TransactionPause::pause_call(
+			Origin::signed(2),
+			Box::new(mock::Call::Balances(pallet_balances::Call::transfer {
+				dest: 42,
+				value: 0,
+			})),
+			REASON.to_vec()
+		));
+		System::assert_last_event(Event::TransactionPause(crate::Event::CallPaused(
+			pallet_index,
+			call_index,
+			info(1, PausedBy::Signed(2)),
+		)));
+		assert_eq!(
+			TransactionPause::paused_call_indices((pallet_index, call_index)),
+			Some(info(1, PausedBy::Signed(2)))
+		);
+		// pausing the same call again is not counted twice
+		assert_ok!(TransactionPause::pause_call(
+			Origin::signed(2),
+			Box::new(BALANCE_TRANSFER.clone()),
+			REASON.to_vec()
+		));
+		assert_eq!(PausedCallIndexCount::<Runtime>::get(), 1);
+		assert!(!<Runtime as frame_system::Config>::BaseCallFilter::contains(BALANCE_TRANSFER));
+		assert!(<Runtime as frame_system::Config>::BaseCallFilter::contains(
+			&mock::Call::Balances(pallet_balances::Call::transfer_all {
+				dest: ALICE,
+				keep_alive: true,
+			})
+		));
+		assert_eq!(
+			TransactionPause::pause_status(BALANCE_TRANSFER),
+			Some(CallFilterStatus::TransactionPaused)
+		);
+
+		assert_noop!(
+			TransactionPause::unpause_call(Origin::signed(2), pallet_index, call_index),
+			BadOrigin
+		);
+		assert_ok!(TransactionPause::unpause_call(
+			RawOrigin::Root.into(),
+			pallet_index,
+			call_index
+		));
+		System::assert_last_event(Event::TransactionPause(crate::Event::CallUnpaused(
+			pallet_index,
+			call_index,
+		)));
+		assert_eq!(PausedCallIndexCount::<Runtime>::get(), 0);
+		assert!(<Runtime as frame_system::Config>::BaseCallFilter::contains(
+			BALANCE_TRANSFER
+		));
+	});
+}
+
+#[test]
+fn stale_pause_entries_are_reported_by_reconcile_migration() {
+	use frame_support::traits::OnRuntimeUpgrade;
+
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(TransactionPause::pause_transaction(
+			RawOrigin::Root.into(),
+			b"Balances".to_vec(),
+			b"transfer".to_vec(),
+			None,
+			REASON.to_vec()
+		));
+		// entries of a pallet renamed by an upgrade
+		PausedTransactions::<Runtime>::insert(key(b"Vesting", b"vest"), info(1, PausedBy::Root));
+		PausedPallets::<Runtime>::insert(name(b"Vesting"), ());
+
+		// the pallet itself does not read every entry on each upgrade
+		System::reset_events();
+		<TransactionPause as OnRuntimeUpgrade>::on_runtime_upgrade();
+		assert!(System::events().is_empty());
+
+		migrations::ReconcilePauseEntries::<Runtime>::on_runtime_upgrade();
+
+		System::assert_has_event(Event::TransactionPause(crate::Event::StalePauseEntry(
+			b"Vesting".to_vec(),
+			Some(b"vest".to_vec()),
+		)));
+		System::assert_last_event(Event::TransactionPause(crate::Event::StalePauseEntry(
+			b"Vesting".to_vec(),
+			None,
+		)));
+		assert_eq!(
+			System::events()
+				.iter()
+				.filter(|record| matches!(
+					record.event,
+					Event::TransactionPause(crate::Event::StalePauseEntry(..))
+				))
+				.count(),
+			2
+		);
+	});
+}
+
+#[test]
+fn strict_pause_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let set_balance = mock::Call::Balances(pallet_balances::Call::set_balance {
+			who: ALICE,
+			new_free: 100,
+			new_reserved: 0,
+		});
+
+		assert_noop!(
+			TransactionPause::set_strict_pause(
+				RawOrigin::Root.into(),
+				b"Balances".to_vec(),
+				b"set_balance".to_vec(),
+				true
+			),
+			Error::<Runtime>::NotPaused
+		);
+
+		assert_ok!(TransactionPause::pause_transaction(
+			RawOrigin::Root.into(),
+			b"Balances".to_vec(),
+			b"set_balance".to_vec(),
+			None,
+			REASON.to_vec()
+		));
+		assert!(!TransactionPause::is_strictly_paused(&set_balance));
+		assert_ok!(TransactionPause::ensure_not_strictly_paused(&set_balance));
+
+		assert_ok!(TransactionPause::set_strict_pause(
+			Origin::signed(2),
+			b"Balances".to_vec(),
+			b"set_balance".to_vec(),
+			true
+		));
+		System::assert_last_event(Event::TransactionPause(crate::Event::StrictPauseSet(
+			b"Balances".to_vec(),
+			b"set_balance".to_vec(),
+			true,
+		)));
+		assert!(TransactionPause::is_strictly_paused(&set_balance));
+		assert_noop!(
+			TransactionPause::ensure_not_strictly_paused(&set_balance),
+			Error::<Runtime>::StrictlyPaused
+		);
+
+		// Pausing again keeps the pause strict.
+		assert_ok!(TransactionPause::pause_transaction(
+			RawOrigin::Root.into(),
+			b"Balances".to_vec(),
+			b"set_balance".to_vec(),
+			None,
+			b"another incident".to_vec()
+		));
+		assert!(TransactionPause::is_strictly_paused(&set_balance));
+
+		assert_noop!(
+			TransactionPause::set_strict_pause(
+				Origin::signed(2),
+				b"Balances".to_vec(),
+				b"set_balance".to_vec(),
+				false
+			),
+			BadOrigin
+		);
+		assert_ok!(TransactionPause::set_strict_pause(
+			RawOrigin::Root.into(),
+			b"Balances".to_vec(),
+			b"set_balance".to_vec(),
+			false
+		));
+		assert!(!TransactionPause::is_strictly_paused(&set_balance));
+		assert_eq!(
+			TransactionPause::paused_transactions(key(b"Balances", b"set_balance"))
+				.map(|info| info.reason.into_inner()),
+			Some(b"another incident".to_vec())
+		);
+
+		// Unpausing drops the strictness with the pause.
+		assert_ok!(TransactionPause::set_strict_pause(
+			RawOrigin::Root.into(),
+			b"Balances".to_vec(),
+			b"set_balance".to_vec(),
+			true
+		));
+		assert_ok!(TransactionPause::unpause_transaction(
+			RawOrigin::Root.into(),
+			b"Balances".to_vec(),
+			b"set_balance".to_vec()
+		));
+		assert!(!TransactionPause::is_strictly_paused(&set_balance));
+	});
+}
+
+#[test]
+fn force_dispatch_paused_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let set_balance = mock::Call::Balances(pallet_balances::Call::set_balance {
+			who: ALICE,
+			new_free: 100,
+			new_reserved: 0,
+		});
+
+		assert_ok!(TransactionPause::pause_transaction(
+			RawOrigin::Root.into(),
+			b"Balances".to_vec(),
+			b"set_balance".to_vec(),
+			None,
+			REASON.to_vec()
+		));
+		assert_noop!(
+			TransactionPause::force_dispatch_paused(
+				RawOrigin::Root.into(),
+				Box::new(set_balance.clone())
+			),
+			Error::<Runtime>::NotStrictlyPaused
+		);
+
+		assert_ok!(TransactionPause::set_strict_pause(
+			RawOrigin::Root.into(),
+			b"Balances".to_vec(),
+			b"set_balance".to_vec(),
+			true
+		));
+		assert_noop!(
+			TransactionPause::force_dispatch_paused(
+				Origin::signed(2),
+				Box::new(set_balance.clone())
+			),
+			BadOrigin
+		);
+		// `Three` stands in for a council which may unpause, but not dispatch as Root
+		assert_noop!(
+			TransactionPause::force_dispatch_paused(
+				Origin::signed(3),
+				Box::new(set_balance.clone())
+			),
+			BadOrigin
+		);
+		assert_ok!(TransactionPause::force_dispatch_paused(
+			RawOrigin::Root.into(),
+			Box::new(set_balance)
+		));
+		System::assert_last_event(Event::TransactionPause(crate::Event::PausedCallDispatched(
+			b"Balances".to_vec(),
+			b"set_balance".to_vec(),
+			Ok(()),
+		)));
+		assert_eq!(Balances::free_balance(ALICE), 100);
+
+		// a failed dispatch is reported, and its weight refunded down to what it used
+		let force_transfer = mock::Call::Balances(pallet_balances::Call::force_transfer {
+			source: 3,
+			dest: ALICE,
+			value: 10,
+		});
+		assert_ok!(TransactionPause::pause_transaction(
+			RawOrigin::Root.into(),
+			b"Balances".to_vec(),
+			b"force_transfer".to_vec(),
+			None,
+			REASON.to_vec()
+		));
+		assert_ok!(TransactionPause::set_strict_pause(
+			RawOrigin::Root.into(),
+			b"Balances".to_vec(),
+			b"force_transfer".to_vec(),
+			true
+		));
+		let post_info = TransactionPause::force_dispatch_paused(
+			RawOrigin::Root.into(),
+			Box::new(force_transfer.clone()),
+		)
+		.unwrap();
+		assert!(matches!(
+			System::events().last().map(|record| &record.event),
+			Some(Event::TransactionPause(crate::Event::PausedCallDispatched(
+				_,
+				_,
+				Err(_)
+			)))
+		));
+		assert!(
+			post_info.actual_weight
+				<= Some(
+					<() as WeightInfo>::force_dispatch_paused()
+						+ force_transfer.get_dispatch_info().weight
+				)
+		);
+		assert_eq!(Balances::free_balance(ALICE), 100);
+
+		// the pause itself is kept
+		assert!(TransactionPause::is_strictly_paused(&mock::Call::Balances(
+			pallet_balances::Call::set_balance {
+				who: ALICE,
+				new_free: 0,
+				new_reserved: 0,
+			}
+		)));
+	});
+}
+
+#[test]
+fn guarded_call_refuses_strictly_paused_call() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let set_balance = mock::Call::Balances(pallet_balances::Call::set_balance {
+			who: ALICE,
+			new_free: 100,
+			new_reserved: 0,
+		});
+		let guarded = GuardedCall::<mock::Call, Runtime>::new(set_balance.clone());
+
+		// it is encoded exactly like the call it guards
+		assert_eq!(guarded.encode(), set_balance.encode());
+		assert_eq!(
+			GuardedCall::<mock::Call, Runtime>::decode(&mut &set_balance.encode()[..]).unwrap(),
+			guarded
+		);
+		let remark: GuardedCall<mock::Call, Runtime> =
+			frame_system::Call::<Runtime>::remark { remark: vec![] }.into();
+		assert_eq!(
+			remark.0,
+			mock::Call::System(frame_system::Call::remark { remark: vec![] })
+		);
+
+		// a pause which is not strict does not stop Root
+		assert_ok!(TransactionPause::pause_transaction(
+			RawOrigin::Root.into(),
+			b"Balances".to_vec(),
+			b"set_balance".to_vec(),
+			None,
+			REASON.to_vec()
+		));
+		assert_ok!(guarded
+			.clone()
+			.dispatch_bypass_filter(RawOrigin::Root.into()));
+		assert_eq!(Balances::free_balance(ALICE), 100);
+
+		assert_ok!(TransactionPause::set_strict_pause(
+			RawOrigin::Root.into(),
+			b"Balances".to_vec(),
+			b"set_balance".to_vec(),
+			true
+		));
+		assert_noop!(
+			guarded
+				.clone()
+				.dispatch_bypass_filter(RawOrigin::Root.into()),
+			Error::<Runtime>::StrictlyPaused
+		);
+		assert_noop!(
+			guarded.dispatch(RawOrigin::Root.into()),
+			Error::<Runtime>::StrictlyPaused
+		);
+	});
+}
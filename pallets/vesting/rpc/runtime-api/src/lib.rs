@@ -0,0 +1,34 @@
+// Copyright 2020-2022 Manta Network.
+// This file is part of Manta.
+//
+// Manta is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Manta is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Manta.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Runtime API definition for the calamari vesting pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use calamari_vesting::{Schedule, VestingInfo};
+use codec::Codec;
+
+sp_api::decl_runtime_apis! {
+	pub trait VestingApi<AccountId, Balance>
+	where
+		AccountId: Codec,
+		Balance: Codec,
+	{
+		/// The vesting state of `who` at `now` of the `VestingClock`, by default its time at
+		/// the block, `None` if it is not vesting.
+		fn vesting_info(who: AccountId, now: Option<Schedule>) -> Option<VestingInfo<Balance>>;
+	}
+}
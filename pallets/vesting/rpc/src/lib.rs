@@ -0,0 +1,100 @@
+// Copyright 2020-2022 Manta Network.
+// This file is part of Manta.
+//
+// Manta is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Manta is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Manta.  If not, see <http://www.gnu.org/licenses/>.
+
+//! RPC interface for the calamari vesting pallet.
+
+use std::sync::Arc;
+
+use codec::Codec;
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+
+pub use calamari_vesting_rpc_runtime_api::{
+	Schedule, VestingApi as VestingRuntimeApi, VestingInfo,
+};
+
+/// Vesting RPC methods.
+#[rpc]
+pub trait VestingApi<BlockHash, AccountId, Balance> {
+	/// The total, vested, locked and claimable amounts of `who` and its next unlock, at the
+	/// time `now` of the runtime `VestingClock`, by default its time at the block.
+	#[rpc(name = "vesting_vestingInfo")]
+	fn vesting_info(
+		&self,
+		who: AccountId,
+		now: Option<Schedule>,
+		at: Option<BlockHash>,
+	) -> Result<Option<VestingInfo<Balance>>>;
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+}
+
+impl From<Error> for i64 {
+	fn from(e: Error) -> i64 {
+		match e {
+			Error::RuntimeError => 1,
+		}
+	}
+}
+
+/// An implementation of vesting specific RPC methods.
+pub struct Vesting<C, B> {
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<B>,
+}
+
+impl<C, B> Vesting<C, B> {
+	/// Create new `Vesting` with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		Self {
+			client,
+			_marker: Default::default(),
+		}
+	}
+}
+
+impl<C, Block, AccountId, Balance> VestingApi<<Block as BlockT>::Hash, AccountId, Balance>
+	for Vesting<C, Block>
+where
+	Block: BlockT,
+	C: 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api: VestingRuntimeApi<Block, AccountId, Balance>,
+	AccountId: Codec,
+	Balance: Codec,
+{
+	fn vesting_info(
+		&self,
+		who: AccountId,
+		now: Option<Schedule>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Option<VestingInfo<Balance>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.vesting_info(&at, who, now).map_err(|e| RpcError {
+			code: ErrorCode::ServerError(Error::RuntimeError.into()),
+			message: "Unable to query vesting info.".into(),
+			data: Some(format!("{:?}", e).into()),
+		})
+	}
+}
@@ -29,12 +29,85 @@ use sp_runtime::{traits::AtLeast32BitUnsigned, SaturatedConversion};
 
 const SEED: u32 = 0;
 // existential deposit multiplier
-const ED_MULTIPLIER: u32 = 100;
+const ED_MULTIPLIER: u32 = 1_000;
 
 fn assert_has_event<T: Config>(generic_event: <T as Config>::Event) {
 	frame_system::Pallet::<T>::assert_has_event(generic_event.into());
 }
 
+/// Give `recipient` `grants` vesting grants of `unvested` on the default schedule from `caller`.
+fn add_grants<T: Config>(
+	caller: &T::AccountId,
+	recipient: &T::AccountId,
+	unvested: BalanceOf<T>,
+	grants: u32,
+	revocable: bool,
+) {
+	for _ in 0..grants {
+		if revocable {
+			assert_ok!(crate::Pallet::<T>::force_vested_transfer(
+				RawOrigin::Root.into(),
+				T::Lookup::unlookup(caller.clone()),
+				T::Lookup::unlookup(recipient.clone()),
+				unvested,
+				None,
+				true
+			));
+		} else {
+			assert_ok!(crate::Pallet::<T>::vested_transfer(
+				RawOrigin::Signed(caller.clone()).into(),
+				T::Lookup::unlookup(recipient.clone()),
+				unvested,
+				None
+			));
+		}
+	}
+}
+
+/// Set both the timestamp and the block number to `secs`, whichever `VestingClock` is used.
+fn set_time<T: pallet_timestamp::Config<Moment = u64>>(secs: u64) {
+	let now = Duration::from_secs(secs)
+		.as_millis()
+		.saturated_into::<u64>()
+		+ 1;
+	pallet_timestamp::Pallet::<T>::set_timestamp(now);
+	frame_system::Pallet::<T>::set_block_number(secs.saturated_into());
+}
+
+/// A valid schedule of the maximum length, with a tranche at each tick from `start`.
+fn schedule_of_max_length<T: Config>(start: Schedule) -> StepsOf<T> {
+	let length = T::MaxScheduleLength::get();
+	let tranche = 100 / length as u8;
+	BoundedVec::try_from(
+		(0..length)
+			.map(|i| {
+				let percentage = if i + 1 == length {
+					100 - tranche * (length as u8 - 1)
+				} else {
+					tranche
+				};
+				(Percent::from_percent(percentage), start + i as u64)
+			})
+			.collect::<sp_std::vec::Vec<_>>(),
+	)
+	.unwrap_or_default()
+}
+
+/// Set a default schedule if the runtime leaves it to governance.
+fn ensure_default_schedule<T: Config>() {
+	if crate::Pallet::<T>::vesting_schedule().is_empty() {
+		VestingSchedule::<T>::put(schedule_of_max_length::<T>(1_000_000));
+	}
+}
+
+/// The time at which everything vests on the default schedule.
+fn last_tranche<T: Config>() -> Schedule {
+	crate::Pallet::<T>::vesting_schedule()
+		.last()
+		.map(|(_, timestamp)| *timestamp)
+		.unwrap_or_default()
+}
+
 fn init_setup<
 	T: Config + pallet_timestamp::Config<Moment = u64> + pallet_balances::Config<I>,
 	I: 'static,
@@ -44,11 +117,9 @@ fn init_setup<
 	<T as pallet_balances::Config>::Balance: TryFrom<u128>,
 	T: pallet_balances::Config,
 {
-	let now = Duration::from_secs(1636329600 - 1)
-		.as_millis()
-		.saturated_into::<u64>()
-		+ 1;
-	pallet_timestamp::Pallet::<T>::set_timestamp(now);
+	ensure_default_schedule::<T>();
+	// just before the first tranche
+	set_time::<T>(crate::Pallet::<T>::vesting_schedule()[0].1 - 1);
 
 	let existential_deposit = <T as pallet_balances::Config<I>>::ExistentialDeposit::get();
 	let amount = existential_deposit.saturating_mul(ED_MULTIPLIER.into());
@@ -76,6 +147,7 @@ benchmarks! {
 	}
 
 	update_vesting_schedule {
+		ensure_default_schedule::<T>();
 		let new_schedule = BoundedVec::try_from(
 			crate::Pallet::<T>::vesting_schedule()
 				.iter()
@@ -91,19 +163,14 @@ benchmarks! {
 	vest {
 		let caller: T::AccountId = whitelisted_caller();
 		let recipient: T::AccountId = account("receiver", 0, SEED);
-		let source_recipient = T::Lookup::unlookup(recipient.clone());
 
 		init_setup::<T, ()>(&caller);
 		let existential_deposit = <T as pallet_balances::Config<()>>::ExistentialDeposit::get();
-		let unvested = existential_deposit.saturating_mul(ED_MULTIPLIER.div(10u32).into()).saturated_into::<u128>().try_into().ok().unwrap();
-		assert_ok!(crate::Pallet::<T>::vested_transfer(RawOrigin::Signed(caller.clone()).into(), source_recipient, unvested));
+		let unvested = existential_deposit.saturating_mul(ED_MULTIPLIER.div(100u32).into()).saturated_into::<u128>().try_into().ok().unwrap();
+		add_grants::<T>(&caller, &recipient, unvested, T::MaxVestingGrants::get(), false);
 		assert!(crate::Pallet::<T>::vesting_balance(&recipient).is_some());
 
-		let now = Duration::from_secs(1660694400)
-			.as_millis()
-			.saturated_into::<u64>()
-			+ 1;
-		pallet_timestamp::Pallet::<T>::set_timestamp(now);
+		set_time::<T>(last_tranche::<T>());
 	}: _(RawOrigin::Signed(recipient.clone()))
 	verify {
 		assert_has_event::<T>(Event::VestingCompleted(recipient).into());
@@ -113,13 +180,132 @@ benchmarks! {
 		let caller: T::AccountId = whitelisted_caller();
 		init_setup::<T, ()>(&caller);
 		let existential_deposit = <T as pallet_balances::Config<()>>::ExistentialDeposit::get();
-		let unvested = existential_deposit.saturating_mul(ED_MULTIPLIER.div(10u32).into()).saturated_into::<u128>().try_into().ok().unwrap();
+		let unvested: BalanceOf<T> = existential_deposit.saturating_mul(ED_MULTIPLIER.div(100u32).into()).saturated_into::<u128>().try_into().ok().unwrap();
+		let recipient: T::AccountId = account("receiver", 0, SEED);
+		let source_recipient = T::Lookup::unlookup(recipient.clone());
+		// the last grant the recipient can get
+		let grants = T::MaxVestingGrants::get();
+		add_grants::<T>(&caller, &recipient, unvested, grants - 1, false);
+		// a custom schedule of the maximum length, which has to be validated
+		let schedule = ScheduleKind::Steps(crate::Pallet::<T>::vesting_schedule());
+		let locked = unvested.max(T::MinCustomVestedTransfer::get());
+	}: _(RawOrigin::Signed(caller.clone()), source_recipient, locked, Some(schedule))
+	verify {
+		let total = unvested.saturating_mul((grants - 1).into()).saturating_add(locked);
+		assert_eq!(crate::Pallet::<T>::vesting_balance(&recipient), Some(total));
+		assert_eq!(crate::Pallet::<T>::vesting_grants(&recipient).len() as u32, grants);
+		assert_has_event::<T>(Event::VestingUpdated(recipient, total).into());
+	}
+
+	merge_grants {
+		let caller: T::AccountId = whitelisted_caller();
+		let recipient: T::AccountId = account("receiver", 0, SEED);
+
+		init_setup::<T, ()>(&caller);
+		let existential_deposit = <T as pallet_balances::Config<()>>::ExistentialDeposit::get();
+		let unvested = existential_deposit.saturating_mul(ED_MULTIPLIER.div(100u32).into()).saturated_into::<u128>().try_into().ok().unwrap();
+		add_grants::<T>(&caller, &recipient, unvested, T::MaxVestingGrants::get(), false);
+	}: _(RawOrigin::Signed(recipient.clone()))
+	verify {
+		assert_has_event::<T>(Event::VestingGrantsMerged(recipient, 1).into());
+	}
+
+	vest_other {
+		let caller: T::AccountId = whitelisted_caller();
+		let recipient: T::AccountId = account("receiver", 0, SEED);
+		let source_recipient = T::Lookup::unlookup(recipient.clone());
+
+		init_setup::<T, ()>(&caller);
+		let existential_deposit = <T as pallet_balances::Config<()>>::ExistentialDeposit::get();
+		let unvested = existential_deposit.saturating_mul(ED_MULTIPLIER.div(100u32).into()).saturated_into::<u128>().try_into().ok().unwrap();
+		add_grants::<T>(&caller, &recipient, unvested, T::MaxVestingGrants::get(), false);
+
+		set_time::<T>(last_tranche::<T>());
+	}: _(RawOrigin::Signed(caller.clone()), source_recipient)
+	verify {
+		assert_has_event::<T>(Event::VestingCompleted(recipient).into());
+	}
+
+	force_vested_transfer {
+		let caller: T::AccountId = whitelisted_caller();
+		init_setup::<T, ()>(&caller);
+		let existential_deposit = <T as pallet_balances::Config<()>>::ExistentialDeposit::get();
+		let unvested: BalanceOf<T> = existential_deposit.saturating_mul(ED_MULTIPLIER.div(100u32).into()).saturated_into::<u128>().try_into().ok().unwrap();
+		let source = T::Lookup::unlookup(caller.clone());
 		let recipient: T::AccountId = account("receiver", 0, SEED);
 		let source_recipient = T::Lookup::unlookup(recipient.clone());
-	}: _(RawOrigin::Signed(caller.clone()), source_recipient, unvested)
+		// the last grant the recipient can get
+		let grants = T::MaxVestingGrants::get();
+		add_grants::<T>(&caller, &recipient, unvested, grants - 1, false);
+		// a custom schedule of the maximum length, which has to be validated
+		let schedule = ScheduleKind::Steps(crate::Pallet::<T>::vesting_schedule());
+	}: _(RawOrigin::Root, source, source_recipient, unvested, Some(schedule), true)
+	verify {
+		let total = unvested.saturating_mul(grants.into());
+		assert_eq!(crate::Pallet::<T>::vesting_balance(&recipient), Some(total));
+		assert_has_event::<T>(Event::VestingUpdated(recipient, total).into());
+	}
+
+	revoke_vesting {
+		let caller: T::AccountId = whitelisted_caller();
+		init_setup::<T, ()>(&caller);
+		let existential_deposit = <T as pallet_balances::Config<()>>::ExistentialDeposit::get();
+		let unvested: BalanceOf<T> = existential_deposit.saturating_mul(ED_MULTIPLIER.div(100u32).into()).saturated_into::<u128>().try_into().ok().unwrap();
+		let recipient: T::AccountId = account("receiver", 0, SEED);
+		let source_recipient = T::Lookup::unlookup(recipient.clone());
+		let beneficiary: T::AccountId = account("beneficiary", 0, SEED);
+		let source_beneficiary = T::Lookup::unlookup(beneficiary.clone());
+		let grants = T::MaxVestingGrants::get();
+		add_grants::<T>(&caller, &recipient, unvested, grants, true);
+	}: _(RawOrigin::Root, source_recipient, source_beneficiary)
+	verify {
+		let total = unvested.saturating_mul(grants.into());
+		assert_has_event::<T>(Event::VestingRevoked(recipient, beneficiary, total).into());
+	}
+
+	replace_vesting_schedule {
+		ensure_default_schedule::<T>();
+		let start = crate::Pallet::<T>::vesting_schedule()[0].1;
+		let new_schedule = schedule_of_max_length::<T>(start);
+	}: _(RawOrigin::Root, new_schedule.clone())
+	verify {
+		assert_has_event::<T>(Event::VestingScheduleReplaced(new_schedule).into());
+	}
+
+	sweep_vesting_account {
+		let caller: T::AccountId = whitelisted_caller();
+		let recipient: T::AccountId = account("receiver", 0, SEED);
+
+		init_setup::<T, ()>(&caller);
+		let existential_deposit = <T as pallet_balances::Config<()>>::ExistentialDeposit::get();
+		let unvested = existential_deposit.saturating_mul(ED_MULTIPLIER.div(100u32).into()).saturated_into::<u128>().try_into().ok().unwrap();
+		add_grants::<T>(&caller, &recipient, unvested, T::MaxVestingGrants::get(), false);
+
+		// the first tranche unlocks
+		set_time::<T>(crate::Pallet::<T>::vesting_schedule()[0].1);
+	}: {
+		crate::Pallet::<T>::sweep_vesting_locks(Weight::MAX);
+	}
+	verify {
+		assert!(crate::Pallet::<T>::sweep_cursor().is_none());
+		assert_has_event::<T>(Event::VestingSweepCompleted.into());
+	}
+
+	batch_vested_transfer {
+		let n in 1 .. T::MaxBatchVestedTransfers::get();
+
+		let caller: T::AccountId = whitelisted_caller();
+		init_setup::<T, ()>(&caller);
+		let existential_deposit = <T as pallet_balances::Config<()>>::ExistentialDeposit::get();
+		let unvested: BalanceOf<T> = existential_deposit.saturating_mul(ED_MULTIPLIER.div(100u32).into()).saturated_into::<u128>().try_into().ok().unwrap();
+		T::Currency::make_free_balance_be(&caller, unvested.saturating_mul((n + 1).into()));
+
+		let transfers = (0..n)
+			.map(|i| (T::Lookup::unlookup(account("receiver", i, SEED)), unvested))
+			.collect::<sp_std::vec::Vec<_>>();
+	}: _(RawOrigin::Signed(caller.clone()), transfers)
 	verify {
-		assert_eq!(crate::Pallet::<T>::vesting_balance(&recipient), Some(unvested));
-		assert_has_event::<T>(Event::VestingUpdated(recipient, unvested).into());
+		assert_has_event::<T>(Event::BatchVestedTransferCompleted(caller, sp_std::vec![]).into());
 	}
 }
 
@@ -18,6 +18,7 @@
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
+pub mod migrations;
 #[cfg(test)]
 mod mock;
 #[cfg(test)]
@@ -32,24 +33,107 @@ use frame_support::{
 		Currency, ExistenceRequirement, Get, LockIdentifier, LockableCurrency, UnixTime,
 		WithdrawReasons,
 	},
+	transactional,
 };
 use frame_system::{ensure_signed, pallet_prelude::*};
 pub use pallet::*;
+use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
 use sp_runtime::{
-	traits::{Saturating, StaticLookup, Zero},
-	Percent,
+	traits::{SaturatedConversion, Saturating, StaticLookup, Zero},
+	Perbill, Percent, RuntimeDebug,
 };
+use sp_std::{marker::PhantomData, vec::Vec};
 
 type BalanceOf<T> =
 	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+/// A point in time of the `VestingClock`.
 pub type Schedule = u64;
+/// The tranches of a step vesting schedule, as the percentage unlocked at each timestamp.
+pub type StepsOf<T> = BoundedVec<(Percent, Schedule), <T as Config>::MaxScheduleLength>;
+/// The vesting schedule of a grant.
+pub type VestingScheduleOf<T> = ScheduleKind<StepsOf<T>>;
+/// A vesting grant of a runtime.
+pub type VestingGrantOf<T> = VestingGrant<BalanceOf<T>, StepsOf<T>>;
+/// The vesting grants of an account.
+pub type GrantsOf<T> = BoundedVec<VestingGrantOf<T>, <T as Config>::MaxVestingGrants>;
 
-const VESTING_ID: LockIdentifier = *b"calamvst";
+/// How the vested balance of an account unlocks.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum ScheduleKind<Steps> {
+	/// A `Percent` of the balance unlocks at each timestamp of `Steps`.
+	Steps(Steps),
+	/// Nothing unlocks before `cliff`, then the balance unlocks linearly per `VestingClock` tick
+	/// from `start` to `end`. The part accrued from `start` to `cliff` unlocks at `cliff`.
+	Linear {
+		start: Schedule,
+		cliff: Schedule,
+		end: Schedule,
+	},
+	/// Follows the default `VestingSchedule`, so that updating or replacing it moves the grant
+	/// too.
+	Default,
+}
+
+/// An amount vested to an account, which unlocks by its own schedule.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct VestingGrant<Balance, Steps> {
+	/// The amount vested by the grant.
+	pub locked: Balance,
+	/// How `locked` unlocks.
+	pub schedule: ScheduleKind<Steps>,
+	/// Whether `ForceOrigin` may revoke the grant.
+	pub revocable: bool,
+}
+
+/// The source of the current time of the vesting schedules.
+///
+/// `ClaimTooEarly` and the validation of schedule updates compare the schedules to it.
+pub trait VestingClock {
+	/// The current time, in the unit of the schedules.
+	fn now() -> Schedule;
+}
+
+/// The seconds of a `UnixTime`, e.g. `pallet_timestamp`.
+pub struct TimestampSeconds<Time>(PhantomData<Time>);
+impl<Time: UnixTime> VestingClock for TimestampSeconds<Time> {
+	fn now() -> Schedule {
+		Time::now().as_secs()
+	}
+}
+
+/// The block number of `frame_system`, to vest by blocks rather than wall-clock time.
+pub struct BlockNumberClock<T>(PhantomData<T>);
+impl<T: frame_system::Config> VestingClock for BlockNumberClock<T> {
+	fn now() -> Schedule {
+		frame_system::Pallet::<T>::block_number().saturated_into()
+	}
+}
+
+/// The vesting state of an account at a timestamp, as reported by `VestingApi`.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct VestingInfo<Balance> {
+	/// The total amount vested by the grants of the account.
+	pub total: Balance,
+	/// The part of `total` vested so far.
+	pub vested: Balance,
+	/// The amount currently locked, as of the last `vest`.
+	pub locked: Balance,
+	/// The amount `vest` would unlock now.
+	pub claimable: Balance,
+	/// The next timestamp at which more of `total` vests, if any.
+	pub next_unlock: Option<Schedule>,
+}
 
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
 
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
 		/// The overarching event type.
@@ -58,61 +142,194 @@ pub mod pallet {
 		/// The currency trait.
 		type Currency: LockableCurrency<Self::AccountId>;
 
-		type Timestamp: UnixTime;
+		/// The clock the vesting schedules are expressed in.
+		type Clock: VestingClock;
+
+		/// The lock identifier of the vested balances.
+		#[pallet::constant]
+		type VestingId: Get<LockIdentifier>;
+
+		/// The default vesting schedule until it is updated, in the unit of `Clock`.
+		///
+		/// It may be empty, to leave it to `replace_vesting_schedule`: no vested transfer on the
+		/// default schedule can be made before.
+		type DefaultVestingSchedule: Get<Vec<(Percent, Schedule)>>;
 
 		/// The minimum amount transferred to call `vested_transfer`.
 		#[pallet::constant]
 		type MinVestedTransfer: Get<BalanceOf<Self>>;
 
+		/// The minimum amount transferred to call `vested_transfer` with a custom schedule.
+		///
+		/// Every custom schedule is a new grant of the target, so this bounds how cheaply
+		/// anyone can fill the `MaxVestingGrants` grants of an account.
+		#[pallet::constant]
+		type MinCustomVestedTransfer: Get<BalanceOf<Self>>;
+
 		/// The maximum length of schedule is allowed.
 		#[pallet::constant]
 		type MaxScheduleLength: Get<u32>;
 
+		/// The maximum number of vesting grants of an account.
+		#[pallet::constant]
+		type MaxVestingGrants: Get<u32>;
+
+		/// The origin which may create vested transfers from any account and revoke grants.
+		///
+		/// It moves the funds of any account, to any account, and may lock them on any schedule,
+		/// so it must be trusted as much as Root. Runtimes should set it to `EnsureRoot`; a
+		/// collective which may only spend the treasury should go through the treasury instead.
+		type ForceOrigin: EnsureOrigin<Self::Origin>;
+
+		/// The origin which may replace the default vesting schedule.
+		type ScheduleOrigin: EnsureOrigin<Self::Origin>;
+
+		/// The maximum weight of the sweep updating the vesting locks in a block.
+		#[pallet::constant]
+		type SweepWeightLimit: Get<Weight>;
+
+		/// The maximum number of transfers in a `batch_vested_transfer`.
+		#[pallet::constant]
+		type MaxBatchVestedTransfers: Get<u32>;
+
 		type WeightInfo: crate::weights::WeightInfo;
 	}
 
-	/// Information regarding the vesting of a given account.
+	/// The vesting grants of a given account, which share its vesting lock.
 	#[pallet::storage]
-	#[pallet::getter(fn vesting_balance)]
-	pub(super) type VestingBalances<T: Config> =
-		StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>>;
+	#[pallet::getter(fn vesting_grants)]
+	pub(super) type VestingGrants<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, GrantsOf<T>, ValueQuery>;
 
-	/// Information regarding the vesting of a given account.
+	/// The default vesting schedule, given to the accounts of a vested transfer without
+	/// a custom schedule.
 	#[pallet::storage]
 	#[pallet::getter(fn vesting_schedule)]
-	pub(super) type VestingSchedule<T: Config> = StorageValue<
-		_,
-		// The schedule is UTC.
-		BoundedVec<(Percent, Schedule), T::MaxScheduleLength>,
-		ValueQuery,
-		DefaultVestingSchedule<T>,
-	>;
+	pub(super) type VestingSchedule<T: Config> =
+		StorageValue<_, StepsOf<T>, ValueQuery, DefaultVestingSchedule<T>>;
 
 	#[pallet::type_value]
-	pub(super) fn DefaultVestingSchedule<T: Config>(
-	) -> BoundedVec<(Percent, Schedule), T::MaxScheduleLength> {
-		BoundedVec::try_from(sp_std::vec![
-			// 1639094400 = 2021-12-10 00:00:00(UTC)
-			(Percent::from_percent(45), 1639094400u64),
-			// 1641340800 = 2022-01-05 00:00:00(UTC)
-			(Percent::from_percent(11), 1641340800u64),
-			// 1646179200 = 2022-03-02 00:00:00(UTC)
-			(Percent::from_percent(11), 1646179200u64),
-			// 1651017600 = 2022-04-27 00:00:00(UTC)
-			(Percent::from_percent(11), 1651017600u64),
-			// 1655856000 = 2022-06-22 00:00:00(UTC)
-			(Percent::from_percent(11), 1655856000u64),
-			// 1660694400 = 2022-08-17 00:00:00(UTC)
-			(Percent::from_percent(11), 1660694400u64),
-		])
-		.unwrap_or_default()
+	pub(super) fn DefaultVestingSchedule<T: Config>() -> StepsOf<T> {
+		BoundedVec::try_from(T::DefaultVestingSchedule::get()).unwrap_or_default()
+	}
+
+	/// The time of the last sweep of the vesting locks.
+	#[pallet::storage]
+	#[pallet::getter(fn last_sweep)]
+	pub(super) type LastSweep<T: Config> = StorageValue<_, Schedule, ValueQuery>;
+
+	/// The next unlock of a grant with a custom schedule, which starts a sweep once it is due.
+	#[pallet::storage]
+	#[pallet::getter(fn next_grant_unlock)]
+	pub(super) type NextGrantUnlock<T: Config> = StorageValue<_, Schedule, OptionQuery>;
+
+	/// The raw key of `VestingGrants` the sweep in progress resumes after, if any.
+	#[pallet::storage]
+	#[pallet::getter(fn sweep_cursor)]
+	pub(super) type SweepCursor<T: Config> = StorageValue<_, Vec<u8>, OptionQuery>;
+
+	#[pallet::genesis_config]
+	pub struct GenesisConfig<T: Config> {
+		/// The default vesting schedule, `DefaultVestingSchedule` if empty.
+		pub vesting_schedule: Vec<(Percent, Schedule)>,
+		/// The vesting accounts with the amount vested on the default schedule.
+		pub vesting: Vec<(T::AccountId, BalanceOf<T>)>,
+	}
+
+	#[cfg(feature = "std")]
+	impl<T: Config> Default for GenesisConfig<T> {
+		fn default() -> Self {
+			Self {
+				vesting_schedule: Default::default(),
+				vesting: Default::default(),
+			}
+		}
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
+		fn build(&self) {
+			if !self.vesting_schedule.is_empty() {
+				let schedule = StepsOf::<T>::try_from(self.vesting_schedule.clone())
+					.expect("vesting schedule is longer than MaxScheduleLength");
+				Pallet::<T>::ensure_valid_schedule(&ScheduleKind::Steps(schedule.clone()))
+					.expect("vesting schedule should be sorted and unlock 100%");
+				VestingSchedule::<T>::put(schedule);
+			}
+
+			if !self.vesting.is_empty() {
+				assert!(
+					Pallet::<T>::ensure_default_schedule().is_ok(),
+					"vesting accounts need a default vesting schedule which has not fully unlocked"
+				);
+			}
+
+			// Nothing is vested before the first block, every grant locks its whole amount.
+			for (who, locked) in &self.vesting {
+				assert!(
+					*locked >= T::MinVestedTransfer::get(),
+					"vesting balance is lower than MinVestedTransfer"
+				);
+				let mut grants = VestingGrants::<T>::get(who);
+				grants
+					.try_push(VestingGrant {
+						locked: *locked,
+						schedule: ScheduleKind::Default,
+						revocable: false,
+					})
+					.expect("too many vesting grants");
+				VestingGrants::<T>::insert(who, grants);
+
+				let total = Pallet::<T>::vesting_balance(who).unwrap_or_default();
+				assert!(
+					T::Currency::free_balance(who) >= total,
+					"endowed balance does not cover the vesting balance"
+				);
+				let reasons = WithdrawReasons::TRANSFER | WithdrawReasons::RESERVE;
+				T::Currency::set_lock(T::VestingId::get(), who, total, reasons);
+			}
+		}
 	}
 
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
 	#[pallet::without_storage_info]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+			Self::sweep_vesting_locks(T::SweepWeightLimit::get())
+		}
+
+		fn integrity_test() {
+			let schedule = StepsOf::<T>::try_from(T::DefaultVestingSchedule::get())
+				.expect("DefaultVestingSchedule is longer than MaxScheduleLength");
+			if schedule.is_empty() {
+				return;
+			}
+			assert!(
+				Self::ensure_valid_schedule(&ScheduleKind::Steps(schedule)).is_ok(),
+				"DefaultVestingSchedule should be sorted and unlock 100%"
+			);
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			migrations::v1::migrate::<T>()
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<(), &'static str> {
+			migrations::v1::pre_migrate::<T>()
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			migrations::v1::post_migrate::<T>()
+		}
+	}
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -125,6 +342,19 @@ pub mod pallet {
 		/// Update a vesting schedule.
 		/// \[new_schedule\]
 		VestingScheduleUpdated(BoundedVec<Schedule, T::MaxScheduleLength>),
+		/// The grants of an account with the same schedule have been merged.
+		/// \[account, grants_left\]
+		VestingGrantsMerged(T::AccountId, u32),
+		/// The revocable grants of an account have been revoked, their locked part has been
+		/// transferred to the beneficiary. \[account, beneficiary, revoked\]
+		VestingRevoked(T::AccountId, T::AccountId, BalanceOf<T>),
+		/// The default vesting schedule has been replaced. \[new_schedule\]
+		VestingScheduleReplaced(StepsOf<T>),
+		/// The sweep has updated the vesting locks of all the accounts.
+		VestingSweepCompleted,
+		/// A batch of vested transfers has been made, but for the skipped ones.
+		/// \[source, skipped_indices\]
+		BatchVestedTransferCompleted(T::AccountId, Vec<u32>),
 	}
 
 	/// Error for the vesting pallet.
@@ -138,7 +368,7 @@ pub mod pallet {
 		AmountLow,
 		/// Not enough tokens for vesting.
 		BalanceLow,
-		/// Cannot input
+		/// Cannot input, or a linear schedule does not have `start <= cliff <= end`.
 		InvalidSchedule,
 		/// The length of new schedule cannot be bigger/smaller than 6.
 		InvalidScheduleLength,
@@ -146,11 +376,24 @@ pub mod pallet {
 		UnsortedSchedule,
 		/// The first round of vesting is not done yet.
 		ClaimTooEarly,
+		/// The percentages of a schedule should add up to 100%.
+		InvalidSchedulePercentage,
+		/// The account already has the maximum number of vesting grants.
+		TooManyGrants,
+		/// The account has no revocable vesting grant.
+		NotRevocable,
+		/// The batch has more than `MaxBatchVestedTransfers` transfers.
+		TooManyVestedTransfers,
+		/// The default vesting schedule is not set, or has fully unlocked.
+		NoDefaultSchedule,
 	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
-		/// Update vesting schedule.
+		/// Update the default vesting schedule.
+		///
+		/// The grants on the default schedule unlock by the updated one, the grants with a custom
+		/// schedule keep it.
 		///
 		/// - `new_schedule`: New schedule for vesting.
 		#[pallet::weight(T::WeightInfo::update_vesting_schedule())]
@@ -173,7 +416,7 @@ pub mod pallet {
 				Error::<T>::UnsortedSchedule
 			);
 
-			let now = T::Timestamp::now().as_secs();
+			let now = T::Clock::now();
 			for (n, o) in new_schedule.iter().zip(old_schedule.iter()) {
 				// n == o means we will partialy update vesting schedule.
 				// n > o means new schedule is future schedule.
@@ -209,105 +452,637 @@ pub mod pallet {
 		#[pallet::weight(T::WeightInfo::vest())]
 		pub fn vest(origin: OriginFor<T>) -> DispatchResult {
 			let who = ensure_signed(origin)?;
-
-			let now = T::Timestamp::now().as_secs();
-			// Ensure signer can claim once time is up to schedule.
-			ensure!(
-				Some(now) >= VestingSchedule::<T>::get().first().map(|v| v.1),
-				Error::<T>::ClaimTooEarly
-			);
-
-			Self::update_lock(&who)
+			Self::do_vest(&who)
 		}
 
 		/// Create a vested transfer: send `target` balance with the vesting schedule.
 		///
-		/// The dispatch origin for this call must be _Signed_.
+		/// The dispatch origin for this call must be _Signed_. The transfer is a new grant of
+		/// `target`, next to the grants it already has. A transfer with a custom schedule must
+		/// be at least `MinCustomVestedTransfer`.
 		///
 		/// - `target`: The account receiving the vested funds.
 		/// - `locked_amount`: How much tokens will be transfered.
+		/// - `schedule`: The vesting schedule of `target`, the default schedule if `None`.
 		#[pallet::weight(T::WeightInfo::vested_transfer())]
 		pub fn vested_transfer(
 			origin: OriginFor<T>,
 			target: <T::Lookup as StaticLookup>::Source,
 			#[pallet::compact] locked_amount: BalanceOf<T>,
+			schedule: Option<VestingScheduleOf<T>>,
 		) -> DispatchResult {
 			let transactor = ensure_signed(origin)?;
-			ensure!(
-				locked_amount >= T::MinVestedTransfer::get(),
-				Error::<T>::AmountLow
-			);
+			let who = T::Lookup::lookup(target)?;
+			if !matches!(schedule, None | Some(ScheduleKind::Default)) {
+				ensure!(
+					locked_amount >= T::MinCustomVestedTransfer::get(),
+					Error::<T>::AmountLow
+				);
+			}
+			Self::do_vested_transfer(&transactor, &who, locked_amount, schedule, false)
+		}
 
-			ensure!(
-				T::Currency::free_balance(&transactor) >= locked_amount,
-				Error::<T>::BalanceLow
-			);
+		/// Merge the vesting grants of the sender which have the same schedule and are either
+		/// all revocable or all not.
+		///
+		/// The dispatch origin for this call must be _Signed_ and the sender must have funds still
+		/// locked under this pallet.
+		///
+		/// Emits `VestingGrantsMerged`, then either `VestingCompleted` or `VestingUpdated`.
+		#[pallet::weight(T::WeightInfo::merge_grants())]
+		pub fn merge_grants(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let grants = VestingGrants::<T>::get(&who);
+			ensure!(!grants.is_empty(), Error::<T>::NotVesting);
+
+			let mut merged: Vec<VestingGrantOf<T>> = Vec::new();
+			for grant in grants {
+				match merged
+					.iter_mut()
+					.find(|m| m.schedule == grant.schedule && m.revocable == grant.revocable)
+				{
+					Some(m) => m.locked = m.locked.saturating_add(grant.locked),
+					None => merged.push(grant),
+				}
+			}
+
+			let grants_left = merged.len() as u32;
+			let merged = GrantsOf::<T>::try_from(merged).map_err(|_| Error::<T>::TooManyGrants)?;
+			VestingGrants::<T>::insert(&who, merged);
+			Self::deposit_event(Event::<T>::VestingGrantsMerged(who.clone(), grants_left));
 
+			Self::update_lock(&who)
+		}
+
+		/// Unlock the vested balance of another account according to its schedule.
+		///
+		/// The dispatch origin for this call must be _Signed_ and `target` must have funds still
+		/// locked under this pallet.
+		///
+		/// - `target`: The account whose vested funds are unlocked.
+		///
+		/// Emits either `VestingCompleted` or `VestingUpdated`.
+		#[pallet::weight(T::WeightInfo::vest_other())]
+		pub fn vest_other(
+			origin: OriginFor<T>,
+			target: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			let who = T::Lookup::lookup(target)?;
+			Self::do_vest(&who)
+		}
+
+		/// Create a vested transfer funded from `source`.
+		///
+		/// The dispatch origin for this call must be `ForceOrigin`.
+		///
+		/// - `source`: The account funding the vested transfer, e.g. the treasury.
+		/// - `target`: The account receiving the vested funds.
+		/// - `locked_amount`: How much tokens will be transfered.
+		/// - `schedule`: The vesting schedule of `target`, the default schedule if `None`.
+		/// - `revocable`: Whether `ForceOrigin` may revoke the grant with `revoke_vesting`.
+		#[pallet::weight(T::WeightInfo::force_vested_transfer())]
+		#[transactional]
+		pub fn force_vested_transfer(
+			origin: OriginFor<T>,
+			source: <T::Lookup as StaticLookup>::Source,
+			target: <T::Lookup as StaticLookup>::Source,
+			#[pallet::compact] locked_amount: BalanceOf<T>,
+			schedule: Option<VestingScheduleOf<T>>,
+			revocable: bool,
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			let source = T::Lookup::lookup(source)?;
 			let who = T::Lookup::lookup(target)?;
+			Self::do_vested_transfer(&source, &who, locked_amount, schedule, revocable)
+		}
+
+		/// Revoke the revocable grants of an account.
+		///
+		/// The part of these grants still locked is transferred to `beneficiary`, the vested part
+		/// stays with `who`, unlocked. The other grants of `who` are kept.
+		///
+		/// The dispatch origin for this call must be `ForceOrigin`.
+		///
+		/// Emits either `VestingCompleted` or `VestingUpdated`, then `VestingRevoked`.
+		#[pallet::weight(T::WeightInfo::revoke_vesting())]
+		#[transactional]
+		pub fn revoke_vesting(
+			origin: OriginFor<T>,
+			who: <T::Lookup as StaticLookup>::Source,
+			beneficiary: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			let who = T::Lookup::lookup(who)?;
+			let beneficiary = T::Lookup::lookup(beneficiary)?;
+
+			let mut grants = VestingGrants::<T>::get(&who);
+			ensure!(!grants.is_empty(), Error::<T>::NotVesting);
 			ensure!(
-				!VestingBalances::<T>::contains_key(&who),
-				Error::<T>::ExistingVestingSchedule
+				grants.iter().any(|grant| grant.revocable),
+				Error::<T>::NotRevocable
 			);
 
+			let now = T::Clock::now();
+			let mut revoked: BalanceOf<T> = Zero::zero();
+			grants.retain(|grant| {
+				if grant.revocable {
+					revoked = revoked.saturating_add(Self::unvested_amount(
+						&grant.schedule,
+						grant.locked,
+						now,
+					));
+				}
+				!grant.revocable
+			});
+
+			// Lock only what the kept grants still vest, so the revoked part can be transferred.
+			if grants.is_empty() {
+				VestingGrants::<T>::remove(&who);
+				T::Currency::remove_lock(T::VestingId::get(), &who);
+				Self::deposit_event(Event::<T>::VestingCompleted(who.clone()));
+			} else {
+				VestingGrants::<T>::insert(&who, grants);
+				Self::update_lock(&who)?;
+			}
+
 			T::Currency::transfer(
-				&transactor,
 				&who,
-				locked_amount,
+				&beneficiary,
+				revoked,
 				ExistenceRequirement::AllowDeath,
 			)?;
+			Self::deposit_event(Event::<T>::VestingRevoked(who, beneficiary, revoked));
+			Ok(())
+		}
+
+		/// Replace the default vesting schedule, with its tranche percentages and length.
+		///
+		/// The tranches already unlocked must be kept as they are, the others must be in the
+		/// future. The grants on the default schedule unlock by the new one, the grants with a
+		/// custom schedule keep it.
+		///
+		/// The dispatch origin for this call must be `ScheduleOrigin`.
+		///
+		/// - `new_schedule`: The new tranches, unlocking 100% in total.
+		#[pallet::weight(T::WeightInfo::replace_vesting_schedule())]
+		pub fn replace_vesting_schedule(
+			origin: OriginFor<T>,
+			new_schedule: StepsOf<T>,
+		) -> DispatchResult {
+			T::ScheduleOrigin::ensure_origin(origin)?;
+			Self::ensure_valid_schedule(&ScheduleKind::Steps(new_schedule.clone()))?;
+
+			let old_schedule = VestingSchedule::<T>::get();
+			let now = T::Clock::now();
+			let unlocked = old_schedule
+				.iter()
+				.take_while(|(_, timestamp)| *timestamp <= now)
+				.count();
+			ensure!(
+				new_schedule.len() >= unlocked
+					&& new_schedule[..unlocked] == old_schedule[..unlocked],
+				Error::<T>::InvalidSchedule
+			);
+			ensure!(
+				new_schedule[unlocked..]
+					.iter()
+					.all(|(_, timestamp)| *timestamp > now),
+				Error::<T>::InvalidSchedule
+			);
+
+			VestingSchedule::<T>::put(new_schedule.clone());
+			Self::deposit_event(Event::VestingScheduleReplaced(new_schedule));
+			Ok(())
+		}
+
+		/// Make a vested transfer on the default schedule to each target of `transfers`.
+		///
+		/// The dispatch origin for this call must be _Signed_, with enough free balance for all
+		/// the transfers. A transfer which cannot be made, e.g. because its amount is below
+		/// `MinVestedTransfer` or its target has too many grants, is skipped rather than
+		/// failing the batch.
+		///
+		/// Emits `BatchVestedTransferCompleted` with the indices of the skipped transfers.
+		///
+		/// - `transfers`: The targets and amounts, at most `MaxBatchVestedTransfers`.
+		#[pallet::weight(T::WeightInfo::batch_vested_transfer(transfers.len() as u32))]
+		pub fn batch_vested_transfer(
+			origin: OriginFor<T>,
+			transfers: Vec<(<T::Lookup as StaticLookup>::Source, BalanceOf<T>)>,
+		) -> DispatchResult {
+			let transactor = ensure_signed(origin)?;
+			ensure!(
+				transfers.len() <= T::MaxBatchVestedTransfers::get() as usize,
+				Error::<T>::TooManyVestedTransfers
+			);
+
+			let total = transfers
+				.iter()
+				.fold(Zero::zero(), |total: BalanceOf<T>, (_, amount)| {
+					total.saturating_add(*amount)
+				});
+			ensure!(
+				T::Currency::free_balance(&transactor) >= total,
+				Error::<T>::BalanceLow
+			);
 
-			Self::new_vesting_account(&who, locked_amount)?;
+			let mut skipped = Vec::new();
+			for (index, (target, amount)) in transfers.into_iter().enumerate() {
+				let transferred = T::Lookup::lookup(target)
+					.map_err(DispatchError::from)
+					.and_then(|who| {
+						Self::do_vested_transfer(&transactor, &who, amount, None, false)
+					});
+				if transferred.is_err() {
+					skipped.push(index as u32);
+				}
+			}
 
+			Self::deposit_event(Event::BatchVestedTransferCompleted(transactor, skipped));
 			Ok(())
 		}
 	}
 }
 
 impl<T: Config> Pallet<T> {
-	/// (Re)set pallet's currency lock on `who`'s account in accordance with their
-	/// current unvested amount.
-	fn update_lock(who: &T::AccountId) -> DispatchResult {
-		let vesting = Self::vesting_balance(&who).ok_or(Error::<T>::NotVesting)?;
-		let now = T::Timestamp::now().as_secs();
-
-		// compute the vested portion
-		let mut portion = Percent::default();
-		for (percentage, timestamp) in VestingSchedule::<T>::get() {
-			if now < timestamp {
-				break;
-			} else {
-				portion = portion.saturating_add(percentage);
+	/// The total amount vested to `who` by its grants, `None` if it is not vesting.
+	pub fn vesting_balance(who: &T::AccountId) -> Option<BalanceOf<T>> {
+		let grants = Self::vesting_grants(who);
+		if grants.is_empty() {
+			return None;
+		}
+		Some(
+			grants
+				.iter()
+				.fold(Zero::zero(), |total: BalanceOf<T>, grant| {
+					total.saturating_add(grant.locked)
+				}),
+		)
+	}
+
+	/// The vesting state of `who` at `now`, `None` if it is not vesting.
+	///
+	/// `locked` is the current vesting lock of `who`, which `LockableCurrency` does not expose.
+	pub fn vesting_info(
+		who: &T::AccountId,
+		now: Schedule,
+		locked: BalanceOf<T>,
+	) -> Option<VestingInfo<BalanceOf<T>>> {
+		let grants = Self::vesting_grants(who);
+		if grants.is_empty() {
+			return None;
+		}
+
+		let mut total: BalanceOf<T> = Zero::zero();
+		let mut unvested: BalanceOf<T> = Zero::zero();
+		for grant in grants.iter() {
+			total = total.saturating_add(grant.locked);
+			unvested =
+				unvested.saturating_add(Self::unvested_amount(&grant.schedule, grant.locked, now));
+		}
+		let next_unlock = grants
+			.iter()
+			.filter_map(|grant| Self::next_unlock(&grant.schedule, now))
+			.min();
+
+		Some(VestingInfo {
+			total,
+			vested: total.saturating_sub(unvested),
+			locked,
+			claimable: locked.saturating_sub(unvested),
+			next_unlock,
+		})
+	}
+
+	/// Unlock the vested balance of `who`, once the first unlock of any of its grants is due.
+	fn do_vest(who: &T::AccountId) -> DispatchResult {
+		let grants = VestingGrants::<T>::get(who);
+		ensure!(!grants.is_empty(), Error::<T>::NotVesting);
+		let now = T::Clock::now();
+		let first_unlock = grants
+			.iter()
+			.filter_map(|grant| Self::first_unlock(&grant.schedule))
+			.min();
+		// Ensure `who` can claim once time is up to the schedule of any grant.
+		ensure!(Some(now) >= first_unlock, Error::<T>::ClaimTooEarly);
+
+		Self::update_lock(who)
+	}
+
+	/// Update the vesting locks of the accounts in `VestingGrants` within `limit`, resuming
+	/// from `SweepCursor`, so that nobody has to call `vest`.
+	///
+	/// A sweep starts once a tranche of the default schedule unlocks after `LastSweep`, or
+	/// once `NextGrantUnlock` is due, and runs across blocks until every account has been
+	/// updated. It finds the next unlock of the grants with a custom schedule on the way.
+	pub(crate) fn sweep_vesting_locks(limit: Weight) -> Weight {
+		let db = T::DbWeight::get();
+		let mut weight = db.reads(2);
+		let mut last_sweep = LastSweep::<T>::get();
+		let mut cursor = match SweepCursor::<T>::get() {
+			Some(cursor) => cursor,
+			None => {
+				weight = weight.saturating_add(db.reads(3));
+				let now = T::Clock::now();
+				let unlocked = VestingSchedule::<T>::get()
+					.iter()
+					.any(|(_, timestamp)| last_sweep < *timestamp && *timestamp <= now)
+					|| NextGrantUnlock::<T>::get().map_or(false, |unlock| unlock <= now);
+				if !unlocked {
+					return weight;
+				}
+				last_sweep = now;
+				LastSweep::<T>::put(now);
+				NextGrantUnlock::<T>::kill();
+				weight = weight.saturating_add(db.writes(2));
+				VestingGrants::<T>::final_prefix().to_vec()
+			}
+		};
+
+		let per_account = T::WeightInfo::sweep_vesting_account();
+		let mut accounts = VestingGrants::<T>::iter_keys_from(cursor.clone());
+		let mut next_unlock = None;
+		loop {
+			if weight
+				.saturating_add(per_account)
+				.saturating_add(db.reads_writes(1, 2))
+				> limit
+			{
+				SweepCursor::<T>::put(cursor);
+				Self::note_grant_unlock(next_unlock);
+				return weight.saturating_add(db.reads_writes(1, 2));
+			}
+			match accounts.next() {
+				Some(who) => {
+					// `who` is vesting, this cannot fail.
+					let _ = Self::update_lock(&who);
+					next_unlock = VestingGrants::<T>::get(&who)
+						.iter()
+						.filter_map(|grant| Self::sweep_point(&grant.schedule, last_sweep))
+						.chain(next_unlock)
+						.min();
+					cursor = VestingGrants::<T>::hashed_key_for(&who);
+					weight = weight.saturating_add(per_account);
+				}
+				None => {
+					SweepCursor::<T>::kill();
+					Self::note_grant_unlock(next_unlock);
+					Self::deposit_event(Event::<T>::VestingSweepCompleted);
+					return weight.saturating_add(db.reads_writes(1, 2));
+				}
 			}
 		}
+	}
 
-		let unvested = (Percent::from_percent(100) - portion) * vesting;
+	/// The first timestamp after `after` at which the sweep updates a grant on `schedule`.
+	///
+	/// The default schedule starts a sweep by itself. A linear schedule is swept at its cliff
+	/// and at its end only, `vest` unlocks what vests in between.
+	fn sweep_point(schedule: &VestingScheduleOf<T>, after: Schedule) -> Option<Schedule> {
+		match schedule {
+			ScheduleKind::Steps(steps) => steps
+				.iter()
+				.map(|(_, timestamp)| *timestamp)
+				.find(|timestamp| *timestamp > after),
+			ScheduleKind::Linear { cliff, end, .. } => {
+				if after < *cliff {
+					Some(*cliff)
+				} else if after < *end {
+					Some(*end)
+				} else {
+					None
+				}
+			}
+			ScheduleKind::Default => None,
+		}
+	}
+
+	/// Bring `NextGrantUnlock` forward to `unlock`, if it is earlier.
+	fn note_grant_unlock(unlock: Option<Schedule>) {
+		if let Some(unlock) = unlock {
+			NextGrantUnlock::<T>::mutate(|next| {
+				*next = Some(next.map_or(unlock, |next| next.min(unlock)))
+			});
+		}
+	}
+
+	/// (Re)set pallet's currency lock on `who`'s account in accordance with their
+	/// current unvested amount, summed across their grants.
+	///
+	/// Fully vested grants are removed.
+	fn update_lock(who: &T::AccountId) -> DispatchResult {
+		let mut grants = Self::vesting_grants(who);
+		ensure!(!grants.is_empty(), Error::<T>::NotVesting);
+		let now = T::Clock::now();
+
+		let mut unvested: BalanceOf<T> = Zero::zero();
+		grants.retain(|grant| {
+			let grant_unvested = Self::unvested_amount(&grant.schedule, grant.locked, now);
+			unvested = unvested.saturating_add(grant_unvested);
+			!grant_unvested.is_zero()
+		});
 
 		if unvested.is_zero() {
-			T::Currency::remove_lock(VESTING_ID, who);
-			VestingBalances::<T>::remove(&who);
+			T::Currency::remove_lock(T::VestingId::get(), who);
+			VestingGrants::<T>::remove(&who);
 			Self::deposit_event(Event::<T>::VestingCompleted(who.clone()));
 		} else {
+			VestingGrants::<T>::insert(&who, grants);
 			let reasons = WithdrawReasons::TRANSFER | WithdrawReasons::RESERVE;
-			T::Currency::set_lock(VESTING_ID, who, unvested, reasons);
+			T::Currency::set_lock(T::VestingId::get(), who, unvested, reasons);
 			Self::deposit_event(Event::<T>::VestingUpdated(who.clone(), unvested));
 		}
 		Ok(())
 	}
 
-	/// Adds a vesting schedule to a given account.
-	fn new_vesting_account(who: &T::AccountId, locked: BalanceOf<T>) -> DispatchResult {
+	/// Transfer `locked_amount` from `source` to `who` as a new grant of `who`.
+	///
+	/// Nothing is changed if it fails, so that `batch_vested_transfer` can skip it.
+	#[transactional]
+	fn do_vested_transfer(
+		source: &T::AccountId,
+		who: &T::AccountId,
+		locked_amount: BalanceOf<T>,
+		schedule: Option<VestingScheduleOf<T>>,
+		revocable: bool,
+	) -> DispatchResult {
+		ensure!(
+			locked_amount >= T::MinVestedTransfer::get(),
+			Error::<T>::AmountLow
+		);
+
+		let schedule = match schedule {
+			None | Some(ScheduleKind::Default) => {
+				Self::ensure_default_schedule()?;
+				ScheduleKind::Default
+			}
+			Some(schedule) => {
+				Self::ensure_valid_schedule(&schedule)?;
+				schedule
+			}
+		};
+
+		ensure!(
+			T::Currency::free_balance(source) >= locked_amount,
+			Error::<T>::BalanceLow
+		);
+
+		ensure!(
+			VestingGrants::<T>::decode_len(who).unwrap_or_default()
+				< T::MaxVestingGrants::get() as usize,
+			Error::<T>::TooManyGrants
+		);
+
+		T::Currency::transfer(source, who, locked_amount, ExistenceRequirement::AllowDeath)?;
+
+		Self::new_vesting_account(who, locked_amount, schedule, revocable)
+	}
+
+	/// Adds a vesting grant to a given account.
+	fn new_vesting_account(
+		who: &T::AccountId,
+		locked: BalanceOf<T>,
+		schedule: VestingScheduleOf<T>,
+		revocable: bool,
+	) -> DispatchResult {
 		if locked.is_zero() {
 			return Ok(());
 		}
 
-		// Ensure current user doesn't have any vested token.
+		Self::note_grant_unlock(Self::sweep_point(&schedule, T::Clock::now()));
+		VestingGrants::<T>::try_mutate(&who, |grants| {
+			grants
+				.try_push(VestingGrant {
+					locked,
+					schedule,
+					revocable,
+				})
+				.map_err(|_| Error::<T>::TooManyGrants)
+		})?;
+		// it can't fail, but even if somehow it did, we don't really care.
+		Self::update_lock(who)
+	}
+
+	/// The amount of `vesting` still locked by `schedule` at `now`.
+	///
+	/// The amount vested by a linear schedule is rounded down, so nothing unlocks before
+	/// it is due.
+	pub fn unvested_amount(
+		schedule: &VestingScheduleOf<T>,
+		vesting: BalanceOf<T>,
+		now: Schedule,
+	) -> BalanceOf<T> {
+		match schedule {
+			ScheduleKind::Steps(steps) => {
+				// compute the vested portion
+				let mut portion = Percent::default();
+				for (percentage, timestamp) in steps.iter() {
+					if now < *timestamp {
+						break;
+					} else {
+						portion = portion.saturating_add(*percentage);
+					}
+				}
+				(Percent::from_percent(100) - portion) * vesting
+			}
+			ScheduleKind::Linear { start, cliff, end } => {
+				if now < *cliff {
+					vesting
+				} else if now >= *end {
+					Zero::zero()
+				} else {
+					let vested = Perbill::from_rational(
+						now.saturating_sub(*start),
+						end.saturating_sub(*start),
+					)
+					.mul_floor(vesting);
+					vesting.saturating_sub(vested)
+				}
+			}
+			ScheduleKind::Default => Self::unvested_amount(
+				&ScheduleKind::Steps(VestingSchedule::<T>::get()),
+				vesting,
+				now,
+			),
+		}
+	}
+
+	/// The first timestamp at which `schedule` unlocks anything.
+	pub fn first_unlock(schedule: &VestingScheduleOf<T>) -> Option<Schedule> {
+		match schedule {
+			ScheduleKind::Steps(steps) => steps.first().map(|v| v.1),
+			ScheduleKind::Linear { cliff, .. } => Some(*cliff),
+			ScheduleKind::Default => VestingSchedule::<T>::get().first().map(|v| v.1),
+		}
+	}
+
+	/// The first timestamp after `now` at which `schedule` unlocks more.
+	///
+	/// A linear schedule unlocks at every tick between its cliff and its end.
+	pub fn next_unlock(schedule: &VestingScheduleOf<T>, now: Schedule) -> Option<Schedule> {
+		match schedule {
+			ScheduleKind::Steps(steps) => steps
+				.iter()
+				.map(|(_, timestamp)| *timestamp)
+				.find(|timestamp| *timestamp > now),
+			ScheduleKind::Linear { cliff, end, .. } => {
+				if now < *cliff {
+					Some(*cliff)
+				} else if now < *end {
+					Some(now + 1)
+				} else {
+					None
+				}
+			}
+			ScheduleKind::Default => {
+				Self::next_unlock(&ScheduleKind::Steps(VestingSchedule::<T>::get()), now)
+			}
+		}
+	}
+
+	/// Ensure the default vesting schedule is set and has a tranche left to unlock, so that
+	/// a grant on it vests something.
+	fn ensure_default_schedule() -> DispatchResult {
+		let now = T::Clock::now();
 		ensure!(
-			!VestingBalances::<T>::contains_key(&who),
-			Error::<T>::ExistingVestingSchedule
+			VestingSchedule::<T>::get()
+				.last()
+				.map_or(false, |(_, timestamp)| *timestamp > now),
+			Error::<T>::NoDefaultSchedule
 		);
+		Ok(())
+	}
 
-		VestingBalances::<T>::insert(&who, locked);
-		// it can't fail, but even if somehow it did, we don't really care.
-		Self::update_lock(who)
+	/// Ensure a custom vesting schedule is valid.
+	///
+	/// A step schedule should be sorted, not empty and unlock 100% in total. A linear schedule
+	/// should have its cliff between its start and its end.
+	fn ensure_valid_schedule(schedule: &VestingScheduleOf<T>) -> DispatchResult {
+		match schedule {
+			ScheduleKind::Steps(steps) => {
+				ensure!(!steps.is_empty(), Error::<T>::InvalidScheduleLength);
+				ensure!(
+					steps.as_slice().windows(2).all(|w| w[0].1 < w[1].1),
+					Error::<T>::UnsortedSchedule
+				);
+				// Add up as integers, a saturated sum of `Percent` would accept more than 100%.
+				let total: u32 = steps
+					.iter()
+					.map(|(percentage, _)| percentage.deconstruct() as u32)
+					.sum();
+				ensure!(total == 100, Error::<T>::InvalidSchedulePercentage);
+			}
+			ScheduleKind::Linear { start, cliff, end } => {
+				ensure!(
+					start <= cliff && cliff <= end && start < end,
+					Error::<T>::InvalidSchedule
+				);
+			}
+			// The default schedule is validated when it is set.
+			ScheduleKind::Default => {}
+		}
+		Ok(())
 	}
 }
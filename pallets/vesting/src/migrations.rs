@@ -0,0 +1,98 @@
+// Copyright 2020-2022 Manta Network.
+// This file is part of Manta.
+//
+// Manta is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Manta is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Manta.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Storage migrations for the calamari vesting pallet.
+
+use super::*;
+use frame_support::{storage::migration::storage_key_iter, traits::GetStorageVersion};
+
+/// Move the `VestingBalances` of the unversioned pallet into one grant per account in
+/// `VestingGrants`.
+pub mod v1 {
+	use super::*;
+
+	const VESTING_BALANCES: &[u8] = b"VestingBalances";
+
+	/// The accounts in `VestingBalances`, with their vested amount.
+	fn vesting_balances<T: Config>() -> impl Iterator<Item = (T::AccountId, BalanceOf<T>)> {
+		storage_key_iter::<T::AccountId, BalanceOf<T>, Blake2_128Concat>(
+			<Pallet<T> as PalletInfoAccess>::name().as_bytes(),
+			VESTING_BALANCES,
+		)
+	}
+
+	/// The accounts vesting before followed the global `VestingSchedule`, their single grant
+	/// follows the default schedule and is not revocable. Their vesting lock is unchanged.
+	///
+	/// `VestingBalances` is drained as it is migrated, `pre_migrate` checks it fits in a block.
+	pub fn migrate<T: Config>() -> Weight {
+		if Pallet::<T>::on_chain_storage_version() >= 1 {
+			return T::DbWeight::get().reads(1);
+		}
+
+		let mut migrated: Weight = 0;
+		for (who, locked) in vesting_balances::<T>().drain() {
+			migrated += 1;
+			let grant = VestingGrant {
+				locked,
+				schedule: ScheduleKind::Default,
+				revocable: false,
+			};
+			if let Ok(grants) = GrantsOf::<T>::try_from(sp_std::vec![grant]) {
+				VestingGrants::<T>::insert(&who, grants);
+			}
+		}
+
+		StorageVersion::new(1).put::<Pallet<T>>();
+		T::DbWeight::get().reads_writes(migrated + 1, 2 * migrated + 1)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	pub fn pre_migrate<T: Config>() -> Result<(), &'static str> {
+		if Pallet::<T>::on_chain_storage_version() >= 1 {
+			return Ok(());
+		}
+		frame_support::ensure!(
+			VestingGrants::<T>::iter_keys().next().is_none(),
+			"some vesting grants exist before the migration"
+		);
+		let accounts = vesting_balances::<T>().count() as Weight;
+		frame_support::ensure!(
+			T::DbWeight::get().reads_writes(accounts + 1, 2 * accounts + 1)
+				<= T::BlockWeights::get().max_block,
+			"VestingBalances is too large to be migrated in a single block"
+		);
+		Ok(())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	pub fn post_migrate<T: Config>() -> Result<(), &'static str> {
+		frame_support::ensure!(
+			Pallet::<T>::on_chain_storage_version() == 1,
+			"storage version of CalamariVesting is not 1"
+		);
+		frame_support::ensure!(
+			vesting_balances::<T>().next().is_none(),
+			"some vesting balances were not migrated"
+		);
+		// every value must decode as `GrantsOf`
+		frame_support::ensure!(
+			VestingGrants::<T>::iter_values().count() == VestingGrants::<T>::iter_keys().count(),
+			"some vesting grants cannot be decoded"
+		);
+		Ok(())
+	}
+}
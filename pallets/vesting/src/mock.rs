@@ -44,7 +44,7 @@ frame_support::construct_runtime!(
 		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
 		Timestamp: pallet_timestamp::{Pallet, Call, Storage, Inherent},
 		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
-		CalamariVesting: calamari_vesting::{Pallet, Call, Storage, Event<T>},
+		CalamariVesting: calamari_vesting::{Pallet, Call, Storage, Config<T>, Event<T>},
 	}
 );
 
@@ -107,26 +107,74 @@ impl pallet_timestamp::Config for Test {
 
 parameter_types! {
 	pub const MinVestedTransfer: Balance = 2;
+	pub const MinCustomVestedTransfer: Balance = 20;
 	pub static ExistentialDeposit: Balance = 1;
 	pub const MaxScheduleLength: u32 = 6;
+	pub const MaxVestingGrants: u32 = 3;
+	pub static SweepWeightLimit: Weight = Weight::MAX;
+	pub static UseBlockNumberClock: bool = false;
+	pub const MaxBatchVestedTransfers: u32 = 4;
+	pub const VestingId: LockIdentifier = *b"calamvst";
+	pub DefaultVestingSchedule: Vec<(Percent, Schedule)> = vec![
+		// 1639094400 = 2021-12-10 00:00:00(UTC)
+		(Percent::from_percent(45), 1639094400u64),
+		// 1641340800 = 2022-01-05 00:00:00(UTC)
+		(Percent::from_percent(11), 1641340800u64),
+		// 1646179200 = 2022-03-02 00:00:00(UTC)
+		(Percent::from_percent(11), 1646179200u64),
+		// 1651017600 = 2022-04-27 00:00:00(UTC)
+		(Percent::from_percent(11), 1651017600u64),
+		// 1655856000 = 2022-06-22 00:00:00(UTC)
+		(Percent::from_percent(11), 1655856000u64),
+		// 1660694400 = 2022-08-17 00:00:00(UTC)
+		(Percent::from_percent(11), 1660694400u64),
+	];
 }
+/// `TimestampSeconds`, or `BlockNumberClock` if `ExtBuilder::block_number_clock` is set.
+pub struct MockClock;
+impl VestingClock for MockClock {
+	fn now() -> Schedule {
+		if UseBlockNumberClock::get() {
+			BlockNumberClock::<Test>::now()
+		} else {
+			TimestampSeconds::<Timestamp>::now()
+		}
+	}
+}
+
 impl Config for Test {
 	type Currency = Balances;
 	type Event = Event;
-	type Timestamp = Timestamp;
+	type Clock = MockClock;
+	type VestingId = VestingId;
+	type DefaultVestingSchedule = DefaultVestingSchedule;
 	type MinVestedTransfer = MinVestedTransfer;
+	type MinCustomVestedTransfer = MinCustomVestedTransfer;
 	type MaxScheduleLength = MaxScheduleLength;
+	type MaxVestingGrants = MaxVestingGrants;
+	type ForceOrigin = frame_system::EnsureRoot<AccountId>;
+	type ScheduleOrigin = frame_system::EnsureRoot<AccountId>;
+	type SweepWeightLimit = SweepWeightLimit;
+	type MaxBatchVestedTransfers = MaxBatchVestedTransfers;
 	type WeightInfo = ();
 }
 
 pub struct ExtBuilder {
 	existential_deposit: Balance,
+	vesting: Vec<(AccountId, Balance)>,
+	sweep_weight_limit: Weight,
+	vesting_schedule: Vec<(Percent, Schedule)>,
+	block_number_clock: bool,
 }
 
 impl Default for ExtBuilder {
 	fn default() -> Self {
 		Self {
 			existential_deposit: 1,
+			vesting: vec![],
+			sweep_weight_limit: Weight::MAX,
+			vesting_schedule: vec![],
+			block_number_clock: false,
 		}
 	}
 }
@@ -137,21 +185,55 @@ impl ExtBuilder {
 		self
 	}
 
+	/// Endow and vest these accounts at genesis.
+	pub fn vesting(mut self, vesting: Vec<(AccountId, Balance)>) -> Self {
+		self.vesting = vesting;
+		self
+	}
+
+	pub fn sweep_weight_limit(mut self, sweep_weight_limit: Weight) -> Self {
+		self.sweep_weight_limit = sweep_weight_limit;
+		self
+	}
+
+	/// Set the default vesting schedule at genesis.
+	pub fn vesting_schedule(mut self, vesting_schedule: Vec<(Percent, Schedule)>) -> Self {
+		self.vesting_schedule = vesting_schedule;
+		self
+	}
+
+	/// Vest by block numbers rather than timestamps.
+	pub fn block_number_clock(mut self) -> Self {
+		self.block_number_clock = true;
+		self
+	}
+
 	pub fn build(self) -> sp_io::TestExternalities {
 		EXISTENTIAL_DEPOSIT.with(|v| *v.borrow_mut() = self.existential_deposit);
+		SWEEP_WEIGHT_LIMIT.with(|v| *v.borrow_mut() = self.sweep_weight_limit);
+		let block_number_clock = self.block_number_clock;
+		USE_BLOCK_NUMBER_CLOCK.with(|v| *v.borrow_mut() = block_number_clock);
 		let mut t = frame_system::GenesisConfig::default()
 			.build_storage::<Test>()
 			.unwrap();
-		pallet_balances::GenesisConfig::<Test> {
-			balances: vec![(ALICE, ALICE_DEPOSIT * self.existential_deposit)],
+		let mut balances = vec![(ALICE, ALICE_DEPOSIT * self.existential_deposit)];
+		balances.extend(self.vesting.iter().cloned());
+		pallet_balances::GenesisConfig::<Test> { balances }
+			.assimilate_storage(&mut t)
+			.unwrap();
+		calamari_vesting::GenesisConfig::<Test> {
+			vesting_schedule: self.vesting_schedule,
+			vesting: self.vesting,
 		}
 		.assimilate_storage(&mut t)
 		.unwrap();
 
 		let mut ext = sp_io::TestExternalities::new(t);
 		ext.execute_with(|| {
-			// Set current time more early than the first schedule.
-			Timestamp::set_timestamp(VestingSchedule::<Test>::get()[0].1 * 1000 - 3 * 6000);
+			if !block_number_clock {
+				// Set current time more early than the first schedule.
+				Timestamp::set_timestamp(VestingSchedule::<Test>::get()[0].1 * 1000 - 3 * 6000);
+			}
 			System::set_block_number(1);
 		});
 		ext
@@ -30,14 +30,20 @@ fn alice_vesting_for_bob_should_work() {
 				CalamariVesting::vested_transfer(
 					Origin::signed(ALICE),
 					BOB,
-					MinVestedTransfer::get() - 1
+					MinVestedTransfer::get() - 1,
+					None
 				),
 				Error::<Test>::AmountLow
 			);
 
 			// Signer cannot vest tokens that exceeds all he has.
 			assert_noop!(
-				CalamariVesting::vested_transfer(Origin::signed(ALICE), BOB, ALICE_DEPOSIT + 1),
+				CalamariVesting::vested_transfer(
+					Origin::signed(ALICE),
+					BOB,
+					ALICE_DEPOSIT + 1,
+					None
+				),
 				Error::<Test>::BalanceLow
 			);
 
@@ -45,18 +51,13 @@ fn alice_vesting_for_bob_should_work() {
 			assert_ok!(CalamariVesting::vested_transfer(
 				Origin::signed(ALICE),
 				BOB,
-				unvested
+				unvested,
+				None
 			));
 
-			// Cannot vest tokens the same user more than twice.
-			assert_noop!(
-				CalamariVesting::vested_transfer(Origin::signed(ALICE), BOB, unvested),
-				Error::<Test>::ExistingVestingSchedule
-			);
-
 			assert_eq!(Balances::free_balance(ALICE), ALICE_DEPOSIT - unvested);
 			assert_eq!(Balances::free_balance(BOB), unvested);
-			assert_eq!(VestingBalances::<Test>::get(BOB), Some(unvested));
+			assert_eq!(CalamariVesting::vesting_balance(&BOB), Some(unvested));
 
 			// Now Bob cannot claim any token.
 			assert_noop!(
@@ -124,7 +125,7 @@ fn alice_vesting_for_bob_should_work() {
 			assert_eq!(Balances::free_balance(BOB), 0);
 
 			// Ensure vesting info is removed once vesting is done.
-			assert_eq!(VestingBalances::<Test>::get(BOB), None);
+			assert_eq!(CalamariVesting::vesting_balance(&BOB), None);
 		});
 }
 
@@ -138,11 +139,12 @@ fn alice_vesting_for_bob_claim_slowly_should_work() {
 			assert_ok!(CalamariVesting::vested_transfer(
 				Origin::signed(ALICE),
 				BOB,
-				unvested
+				unvested,
+				None
 			));
 			assert_eq!(Balances::free_balance(ALICE), ALICE_DEPOSIT - unvested);
 			assert_eq!(Balances::free_balance(BOB), unvested);
-			assert_eq!(VestingBalances::<Test>::get(BOB), Some(unvested));
+			assert_eq!(CalamariVesting::vesting_balance(&BOB), Some(unvested));
 
 			// Now Bob cannot claim any token.
 			assert_noop!(
@@ -194,11 +196,12 @@ fn alice_vesting_for_bob_claim_arbitrarily_should_work() {
 			assert_ok!(CalamariVesting::vested_transfer(
 				Origin::signed(ALICE),
 				BOB,
-				unvested
+				unvested,
+				None
 			));
 			assert_eq!(Balances::free_balance(ALICE), ALICE_DEPOSIT - unvested);
 			assert_eq!(Balances::free_balance(BOB), unvested);
-			assert_eq!(VestingBalances::<Test>::get(BOB), Some(unvested));
+			assert_eq!(CalamariVesting::vesting_balance(&BOB), Some(unvested));
 
 			run_to_block(3);
 			// Ensure current timestamp is bigger than the 1th round of schedule.
@@ -282,10 +285,11 @@ fn vesting_complete_should_work() {
 			assert_ok!(CalamariVesting::vested_transfer(
 				Origin::signed(ALICE),
 				BOB,
-				unvested
+				unvested,
+				None
 			));
 			assert_eq!(Balances::free_balance(ALICE), ALICE_DEPOSIT - unvested);
-			assert_eq!(VestingBalances::<Test>::get(BOB), Some(unvested));
+			assert_eq!(CalamariVesting::vesting_balance(&BOB), Some(unvested));
 
 			// Now Bob cannot claim any token.
 			assert_noop!(
@@ -325,7 +329,7 @@ fn vesting_complete_should_work() {
 			assert_eq!(Balances::free_balance(BOB), 0);
 
 			// Ensure vesting info is removed once vesting is done.
-			assert_eq!(VestingBalances::<Test>::get(BOB), None);
+			assert_eq!(CalamariVesting::vesting_balance(&BOB), None);
 		});
 }
 
@@ -528,3 +532,1311 @@ fn check_vesting_schedule() {
 			}
 		});
 }
+
+#[test]
+fn vesting_with_custom_schedule_should_work() {
+	ExtBuilder::default()
+		.existential_deposit(1)
+		.build()
+		.execute_with(|| {
+			let start = VestingSchedule::<Test>::get()[0].1;
+			let custom_schedule = ScheduleKind::Steps(
+				BoundedVec::try_from(vec![
+					(Percent::from_percent(25), start + 100),
+					(Percent::from_percent(75), start + 200),
+				])
+				.unwrap(),
+			);
+
+			// A custom schedule needs a larger transfer than the default one.
+			assert_noop!(
+				CalamariVesting::vested_transfer(
+					Origin::signed(ALICE),
+					BOB,
+					MinCustomVestedTransfer::get() - 1,
+					Some(custom_schedule.clone())
+				),
+				Error::<Test>::AmountLow
+			);
+
+			let unvested = 100;
+			assert_ok!(CalamariVesting::vested_transfer(
+				Origin::signed(ALICE),
+				BOB,
+				unvested,
+				Some(custom_schedule.clone())
+			));
+			assert_eq!(
+				CalamariVesting::vesting_grants(BOB).into_inner(),
+				vec![VestingGrant {
+					locked: unvested,
+					schedule: custom_schedule,
+					revocable: false,
+				}]
+			);
+
+			// The default schedule has started, but not the one of Bob.
+			Timestamp::set_timestamp(start * 1000 + 1);
+			assert_noop!(
+				CalamariVesting::vest(Origin::signed(BOB)),
+				Error::<Test>::ClaimTooEarly,
+			);
+
+			Timestamp::set_timestamp((start + 100) * 1000 + 1);
+			assert_ok!(CalamariVesting::vest(Origin::signed(BOB)));
+			System::assert_has_event(MockEvent::CalamariVesting(PalletEvent::VestingUpdated(
+				BOB, 75,
+			)));
+
+			Timestamp::set_timestamp((start + 200) * 1000 + 1);
+			assert_ok!(CalamariVesting::vest(Origin::signed(BOB)));
+			System::assert_has_event(MockEvent::CalamariVesting(PalletEvent::VestingCompleted(
+				BOB,
+			)));
+			assert_eq!(CalamariVesting::vesting_balance(&BOB), None);
+			assert!(CalamariVesting::vesting_grants(BOB).is_empty());
+		});
+}
+
+#[test]
+fn invalid_custom_schedule_should_not_work() {
+	ExtBuilder::default()
+		.existential_deposit(1)
+		.build()
+		.execute_with(|| {
+			let schedule = |tranches: Vec<(u8, u64)>| {
+				Some(ScheduleKind::Steps(
+					BoundedVec::try_from(
+						tranches
+							.into_iter()
+							.map(|(p, s)| (Percent::from_percent(p), s))
+							.collect::<Vec<_>>(),
+					)
+					.unwrap(),
+				))
+			};
+
+			assert_noop!(
+				CalamariVesting::force_vested_transfer(
+					Origin::root(),
+					ALICE,
+					BOB,
+					100,
+					schedule(vec![]),
+					false
+				),
+				Error::<Test>::InvalidScheduleLength,
+			);
+			assert_noop!(
+				CalamariVesting::force_vested_transfer(
+					Origin::root(),
+					ALICE,
+					BOB,
+					100,
+					schedule(vec![(50, 2), (50, 1)]),
+					false
+				),
+				Error::<Test>::UnsortedSchedule,
+			);
+			assert_noop!(
+				CalamariVesting::force_vested_transfer(
+					Origin::root(),
+					ALICE,
+					BOB,
+					100,
+					schedule(vec![(50, 1), (40, 2)]),
+					false
+				),
+				Error::<Test>::InvalidSchedulePercentage,
+			);
+			assert_noop!(
+				CalamariVesting::force_vested_transfer(
+					Origin::root(),
+					ALICE,
+					BOB,
+					100,
+					schedule(vec![(60, 1), (60, 2)]),
+					false
+				),
+				Error::<Test>::InvalidSchedulePercentage,
+			);
+		});
+}
+
+#[test]
+fn updating_default_schedule_moves_default_grants() {
+	ExtBuilder::default()
+		.existential_deposit(1)
+		.build()
+		.execute_with(|| {
+			let old_schedule = VestingSchedule::<Test>::get();
+			let custom_schedule = ScheduleKind::Steps(old_schedule.clone());
+			assert_ok!(CalamariVesting::vested_transfer(
+				Origin::signed(ALICE),
+				BOB,
+				100,
+				None
+			));
+			assert_ok!(CalamariVesting::force_vested_transfer(
+				Origin::root(),
+				ALICE,
+				BOB,
+				100,
+				Some(custom_schedule.clone()),
+				false
+			));
+			assert_eq!(
+				CalamariVesting::vesting_grants(BOB)
+					.iter()
+					.map(|grant| grant.schedule.clone())
+					.collect::<Vec<_>>(),
+				vec![ScheduleKind::Default, custom_schedule.clone()]
+			);
+
+			// Postpone every tranche.
+			let delay = 1000;
+			let new_schedule = BoundedVec::try_from(
+				old_schedule
+					.iter()
+					.map(|(_, s)| s + delay)
+					.collect::<Vec<u64>>(),
+			)
+			.unwrap();
+			assert_ok!(CalamariVesting::update_vesting_schedule(
+				Origin::root(),
+				new_schedule
+			));
+			assert_eq!(
+				CalamariVesting::vesting_grants(BOB)[1].schedule,
+				custom_schedule
+			);
+
+			// Only the custom grant unlocks at the old first tranche.
+			let first_unlocked = old_schedule[0].0 * 100;
+			Timestamp::set_timestamp(old_schedule[0].1 * 1000 + 1);
+			assert_ok!(CalamariVesting::vest(Origin::signed(BOB)));
+			System::assert_last_event(MockEvent::CalamariVesting(PalletEvent::VestingUpdated(
+				BOB,
+				200 - first_unlocked,
+			)));
+
+			// The default grant unlocks at the new first tranche.
+			Timestamp::set_timestamp((old_schedule[0].1 + delay) * 1000 + 1);
+			assert_ok!(CalamariVesting::vest(Origin::signed(BOB)));
+			System::assert_last_event(MockEvent::CalamariVesting(PalletEvent::VestingUpdated(
+				BOB,
+				200 - 2 * first_unlocked,
+			)));
+		});
+}
+
+#[test]
+fn migrate_to_vesting_grants_work() {
+	use frame_support::{
+		storage::migration::{get_storage_value, put_storage_value},
+		traits::GetStorageVersion,
+		StorageHasher,
+	};
+
+	ExtBuilder::default()
+		.existential_deposit(1)
+		.build()
+		.execute_with(|| {
+			// An account vesting before the migration, on the global schedule.
+			let hashed_key = BOB.using_encoded(Blake2_128Concat::hash);
+			put_storage_value(b"CalamariVesting", b"VestingBalances", &hashed_key, 100u128);
+			StorageVersion::new(0).put::<CalamariVesting>();
+
+			migrations::v1::migrate::<Test>();
+
+			assert_eq!(CalamariVesting::on_chain_storage_version(), 1);
+			assert_eq!(
+				get_storage_value::<u128>(b"CalamariVesting", b"VestingBalances", &hashed_key),
+				None
+			);
+			let grants = vec![VestingGrant {
+				locked: 100,
+				schedule: ScheduleKind::Default,
+				revocable: false,
+			}];
+			assert_eq!(CalamariVesting::vesting_grants(BOB).into_inner(), grants);
+
+			// Migrating again is a no-op.
+			put_storage_value(b"CalamariVesting", b"VestingBalances", &hashed_key, 200u128);
+			migrations::v1::migrate::<Test>();
+			assert_eq!(CalamariVesting::on_chain_storage_version(), 1);
+			assert_eq!(CalamariVesting::vesting_grants(BOB).into_inner(), grants);
+		});
+}
+
+#[test]
+fn linear_vesting_should_work() {
+	ExtBuilder::default()
+		.existential_deposit(1)
+		.build()
+		.execute_with(|| {
+			let start = VestingSchedule::<Test>::get()[0].1;
+			let (cliff, end) = (start + 10, start + 30);
+			let unvested = 100;
+			assert_ok!(CalamariVesting::force_vested_transfer(
+				Origin::root(),
+				ALICE,
+				BOB,
+				unvested,
+				Some(ScheduleKind::Linear { start, cliff, end }),
+				false
+			));
+			System::assert_has_event(MockEvent::CalamariVesting(PalletEvent::VestingUpdated(
+				BOB, unvested,
+			)));
+
+			// Nothing unlocks before the cliff, even though the schedule has started.
+			Timestamp::set_timestamp(cliff * 1000 - 1);
+			assert_noop!(
+				CalamariVesting::vest(Origin::signed(BOB)),
+				Error::<Test>::ClaimTooEarly,
+			);
+
+			// At the cliff, what accrued since the start unlocks at once: 10 / 30 of 100,
+			// rounded down.
+			Timestamp::set_timestamp(cliff * 1000);
+			assert_ok!(CalamariVesting::vest(Origin::signed(BOB)));
+			System::assert_has_event(MockEvent::CalamariVesting(PalletEvent::VestingUpdated(
+				BOB, 67,
+			)));
+			assert_noop!(
+				Balances::transfer(Origin::signed(BOB), ALICE, 34),
+				pallet_balances::Error::<Test, _>::LiquidityRestrictions,
+			);
+
+			// Then it unlocks per second, milliseconds do not count.
+			Timestamp::set_timestamp((start + 20) * 1000 + 999);
+			assert_ok!(CalamariVesting::vest(Origin::signed(BOB)));
+			System::assert_has_event(MockEvent::CalamariVesting(PalletEvent::VestingUpdated(
+				BOB, 34,
+			)));
+
+			// One second before the end, 29 / 30 of 100 is rounded down.
+			Timestamp::set_timestamp((end - 1) * 1000);
+			assert_ok!(CalamariVesting::vest(Origin::signed(BOB)));
+			System::assert_has_event(MockEvent::CalamariVesting(PalletEvent::VestingUpdated(
+				BOB, 4,
+			)));
+
+			Timestamp::set_timestamp(end * 1000);
+			assert_ok!(CalamariVesting::vest(Origin::signed(BOB)));
+			System::assert_has_event(MockEvent::CalamariVesting(PalletEvent::VestingCompleted(
+				BOB,
+			)));
+			assert_ok!(Balances::transfer(Origin::signed(BOB), ALICE, unvested));
+		});
+}
+
+#[test]
+fn step_vesting_unlocks_at_its_timestamp() {
+	ExtBuilder::default()
+		.existential_deposit(1)
+		.build()
+		.execute_with(|| {
+			let schedule = VestingSchedule::<Test>::get();
+			let unvested = 99;
+			assert_ok!(CalamariVesting::vested_transfer(
+				Origin::signed(ALICE),
+				BOB,
+				unvested,
+				None
+			));
+
+			// One millisecond before the first round.
+			Timestamp::set_timestamp(schedule[0].1 * 1000 - 1);
+			assert_noop!(
+				CalamariVesting::vest(Origin::signed(BOB)),
+				Error::<Test>::ClaimTooEarly,
+			);
+
+			// Exactly at the first round, 45% of 99 is 44.55, the unvested 55% is 54.45,
+			// rounded to 54.
+			Timestamp::set_timestamp(schedule[0].1 * 1000);
+			assert_ok!(CalamariVesting::vest(Origin::signed(BOB)));
+			System::assert_has_event(MockEvent::CalamariVesting(PalletEvent::VestingUpdated(
+				BOB, 54,
+			)));
+
+			// Exactly at the second round, the unvested 44% of 99 is 43.56, rounded to 44.
+			Timestamp::set_timestamp(schedule[1].1 * 1000);
+			assert_ok!(CalamariVesting::vest(Origin::signed(BOB)));
+			System::assert_has_event(MockEvent::CalamariVesting(PalletEvent::VestingUpdated(
+				BOB, 44,
+			)));
+		});
+}
+
+#[test]
+fn invalid_linear_schedule_should_not_work() {
+	ExtBuilder::default()
+		.existential_deposit(1)
+		.build()
+		.execute_with(|| {
+			for (start, cliff, end) in [(10, 5, 20), (10, 25, 20), (10, 10, 10)] {
+				assert_noop!(
+					CalamariVesting::force_vested_transfer(
+						Origin::root(),
+						ALICE,
+						BOB,
+						100,
+						Some(ScheduleKind::Linear { start, cliff, end }),
+						false
+					),
+					Error::<Test>::InvalidSchedule,
+				);
+			}
+		});
+}
+
+#[test]
+fn multiple_grants_should_work() {
+	ExtBuilder::default()
+		.existential_deposit(1)
+		.build()
+		.execute_with(|| {
+			let start = VestingSchedule::<Test>::get()[0].1;
+			let linear = ScheduleKind::Linear {
+				start,
+				cliff: start,
+				end: start + 100,
+			};
+			for (amount, schedule) in [(100, None), (200, Some(linear)), (50, None)] {
+				assert_ok!(CalamariVesting::vested_transfer(
+					Origin::signed(ALICE),
+					BOB,
+					amount,
+					schedule
+				));
+			}
+			assert_eq!(CalamariVesting::vesting_grants(BOB).len(), 3);
+			assert_eq!(CalamariVesting::vesting_balance(&BOB), Some(350));
+			System::assert_last_event(MockEvent::CalamariVesting(PalletEvent::VestingUpdated(
+				BOB, 350,
+			)));
+
+			// Bob has the maximum number of grants.
+			assert_noop!(
+				CalamariVesting::vested_transfer(Origin::signed(ALICE), BOB, 100, None),
+				Error::<Test>::TooManyGrants
+			);
+
+			// The unvested amounts of all grants add up in the single lock:
+			// 55% of 100, 50% of 200 and 55% of 50, rounded down from 27.5.
+			Timestamp::set_timestamp((start + 50) * 1000);
+			assert_ok!(CalamariVesting::vest(Origin::signed(BOB)));
+			System::assert_last_event(MockEvent::CalamariVesting(PalletEvent::VestingUpdated(
+				BOB,
+				55 + 100 + 27,
+			)));
+			assert_noop!(
+				Balances::transfer(Origin::signed(BOB), ALICE, 350 - 182 + 1),
+				pallet_balances::Error::<Test, _>::LiquidityRestrictions,
+			);
+
+			// A fully vested grant is removed, which makes room for a new one.
+			Timestamp::set_timestamp((start + 100) * 1000);
+			assert_ok!(CalamariVesting::vest(Origin::signed(BOB)));
+			assert_eq!(CalamariVesting::vesting_grants(BOB).len(), 2);
+			assert_eq!(CalamariVesting::vesting_balance(&BOB), Some(150));
+			assert_ok!(CalamariVesting::vested_transfer(
+				Origin::signed(ALICE),
+				BOB,
+				100,
+				None
+			));
+		});
+}
+
+#[test]
+fn merge_grants_should_work() {
+	ExtBuilder::default()
+		.existential_deposit(1)
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				CalamariVesting::merge_grants(Origin::signed(BOB)),
+				Error::<Test>::NotVesting
+			);
+
+			let start = VestingSchedule::<Test>::get()[0].1;
+			let linear = ScheduleKind::Linear {
+				start,
+				cliff: start,
+				end: start + 100,
+			};
+			for (amount, schedule) in [(100, None), (200, Some(linear.clone())), (50, None)] {
+				assert_ok!(CalamariVesting::vested_transfer(
+					Origin::signed(ALICE),
+					BOB,
+					amount,
+					schedule
+				));
+			}
+
+			assert_ok!(CalamariVesting::merge_grants(Origin::signed(BOB)));
+			System::assert_has_event(MockEvent::CalamariVesting(
+				PalletEvent::VestingGrantsMerged(BOB, 2),
+			));
+			assert_eq!(
+				CalamariVesting::vesting_grants(BOB).into_inner(),
+				vec![
+					VestingGrant {
+						locked: 150,
+						schedule: ScheduleKind::Default,
+						revocable: false,
+					},
+					VestingGrant {
+						locked: 200,
+						schedule: linear,
+						revocable: false,
+					},
+				]
+			);
+			// The lock is unchanged.
+			System::assert_last_event(MockEvent::CalamariVesting(PalletEvent::VestingUpdated(
+				BOB, 350,
+			)));
+		});
+}
+
+#[test]
+fn vest_other_should_work() {
+	ExtBuilder::default()
+		.existential_deposit(1)
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				CalamariVesting::vest_other(Origin::signed(ALICE), BOB),
+				Error::<Test>::NotVesting,
+			);
+
+			let unvested = 100;
+			assert_ok!(CalamariVesting::vested_transfer(
+				Origin::signed(ALICE),
+				BOB,
+				unvested,
+				None
+			));
+
+			// Alice cannot unlock the tokens of Bob before the first round either.
+			assert_noop!(
+				CalamariVesting::vest_other(Origin::signed(ALICE), BOB),
+				Error::<Test>::ClaimTooEarly,
+			);
+
+			let first_round = 0;
+			let now = VestingSchedule::<Test>::get()[first_round].1 * 1000 + 1;
+			Timestamp::set_timestamp(now);
+
+			assert_ok!(CalamariVesting::vest_other(Origin::signed(ALICE), BOB));
+			let vested = VestingSchedule::<Test>::get()[first_round].0 * unvested;
+			System::assert_has_event(MockEvent::CalamariVesting(PalletEvent::VestingUpdated(
+				BOB,
+				unvested - vested,
+			)));
+			assert_ok!(Balances::transfer(Origin::signed(BOB), ALICE, vested));
+
+			let last_round = 5;
+			let now = VestingSchedule::<Test>::get()[last_round].1 * 1000 + 1;
+			Timestamp::set_timestamp(now);
+
+			assert_ok!(CalamariVesting::vest_other(Origin::signed(ALICE), BOB));
+			System::assert_has_event(MockEvent::CalamariVesting(PalletEvent::VestingCompleted(
+				BOB,
+			)));
+			assert_eq!(CalamariVesting::vesting_balance(&BOB), None);
+		});
+}
+
+#[test]
+fn force_vested_transfer_should_work() {
+	ExtBuilder::default()
+		.existential_deposit(1)
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				CalamariVesting::force_vested_transfer(
+					Origin::signed(ALICE),
+					ALICE,
+					BOB,
+					100,
+					None,
+					true
+				),
+				sp_runtime::traits::BadOrigin
+			);
+			assert_noop!(
+				CalamariVesting::force_vested_transfer(Origin::root(), BOB, ALICE, 100, None, true),
+				Error::<Test>::BalanceLow
+			);
+
+			assert_ok!(CalamariVesting::force_vested_transfer(
+				Origin::root(),
+				ALICE,
+				BOB,
+				100,
+				None,
+				true
+			));
+			assert_eq!(Balances::free_balance(ALICE), ALICE_DEPOSIT - 100);
+			assert_eq!(
+				CalamariVesting::vesting_grants(BOB).into_inner(),
+				vec![VestingGrant {
+					locked: 100,
+					schedule: ScheduleKind::Default,
+					revocable: true,
+				}]
+			);
+			System::assert_last_event(MockEvent::CalamariVesting(PalletEvent::VestingUpdated(
+				BOB, 100,
+			)));
+		});
+}
+
+#[test]
+fn revoke_vesting_should_work() {
+	ExtBuilder::default()
+		.existential_deposit(1)
+		.build()
+		.execute_with(|| {
+			const TREASURY: AccountId = 3;
+
+			// A grant from Alice herself cannot be revoked.
+			assert_ok!(CalamariVesting::vested_transfer(
+				Origin::signed(ALICE),
+				BOB,
+				100,
+				None
+			));
+			assert_noop!(
+				CalamariVesting::revoke_vesting(Origin::root(), BOB, TREASURY),
+				Error::<Test>::NotRevocable
+			);
+			assert_ok!(CalamariVesting::force_vested_transfer(
+				Origin::root(),
+				ALICE,
+				BOB,
+				200,
+				None,
+				true
+			));
+			assert_noop!(
+				CalamariVesting::revoke_vesting(Origin::signed(ALICE), BOB, TREASURY),
+				sp_runtime::traits::BadOrigin
+			);
+
+			// The first round has vested.
+			let first_round = 0;
+			let now = VestingSchedule::<Test>::get()[first_round].1 * 1000 + 1;
+			Timestamp::set_timestamp(now);
+
+			assert_ok!(CalamariVesting::revoke_vesting(
+				Origin::root(),
+				BOB,
+				TREASURY
+			));
+			// The unvested 55% of the revocable grant goes to the treasury.
+			System::assert_has_event(MockEvent::CalamariVesting(PalletEvent::VestingRevoked(
+				BOB, TREASURY, 110,
+			)));
+			assert_eq!(Balances::free_balance(TREASURY), 110);
+			assert_eq!(Balances::free_balance(BOB), 300 - 110);
+			// Only the other grant still locks.
+			System::assert_has_event(MockEvent::CalamariVesting(PalletEvent::VestingUpdated(
+				BOB, 55,
+			)));
+			assert_eq!(CalamariVesting::vesting_balance(&BOB), Some(100));
+			assert_ok!(Balances::transfer(
+				Origin::signed(BOB),
+				ALICE,
+				300 - 110 - 55
+			));
+
+			assert_noop!(
+				CalamariVesting::revoke_vesting(Origin::root(), BOB, TREASURY),
+				Error::<Test>::NotRevocable
+			);
+		});
+}
+
+#[test]
+fn revoking_all_grants_completes_vesting() {
+	ExtBuilder::default()
+		.existential_deposit(1)
+		.build()
+		.execute_with(|| {
+			const TREASURY: AccountId = 3;
+
+			assert_ok!(CalamariVesting::force_vested_transfer(
+				Origin::root(),
+				ALICE,
+				BOB,
+				200,
+				None,
+				true
+			));
+			assert_ok!(CalamariVesting::revoke_vesting(
+				Origin::root(),
+				BOB,
+				TREASURY
+			));
+
+			System::assert_has_event(MockEvent::CalamariVesting(PalletEvent::VestingCompleted(
+				BOB,
+			)));
+			System::assert_last_event(MockEvent::CalamariVesting(PalletEvent::VestingRevoked(
+				BOB, TREASURY, 200,
+			)));
+			assert_eq!(Balances::free_balance(TREASURY), 200);
+			assert_eq!(Balances::free_balance(BOB), 0);
+			assert_eq!(CalamariVesting::vesting_balance(&BOB), None);
+		});
+}
+
+#[test]
+fn genesis_vesting_should_work() {
+	ExtBuilder::default()
+		.existential_deposit(1)
+		.vesting(vec![(BOB, 100)])
+		.build()
+		.execute_with(|| {
+			assert_eq!(Balances::free_balance(BOB), 100);
+			assert_eq!(CalamariVesting::vesting_balance(&BOB), Some(100));
+			assert_eq!(
+				CalamariVesting::vesting_grants(BOB)[0].schedule,
+				ScheduleKind::Default
+			);
+			// The whole genesis balance is locked.
+			assert!(Balances::transfer(Origin::signed(BOB), ALICE, 1).is_err());
+
+			run_to_block(3);
+			let now = VestingSchedule::<Test>::get()[0].1 * 1000 + 1;
+			Timestamp::set_timestamp(now);
+			assert_ok!(CalamariVesting::vest(Origin::signed(BOB)));
+			let unlocked = VestingSchedule::<Test>::get()[0].0 * 100;
+			System::assert_has_event(MockEvent::CalamariVesting(PalletEvent::VestingUpdated(
+				BOB,
+				100 - unlocked,
+			)));
+			assert_ok!(Balances::transfer(Origin::signed(BOB), ALICE, unlocked));
+		});
+}
+
+#[test]
+#[should_panic(expected = "endowed balance does not cover the vesting balance")]
+fn genesis_vesting_over_endowment_should_fail() {
+	let mut t = frame_system::GenesisConfig::default()
+		.build_storage::<Test>()
+		.unwrap();
+	crate::GenesisConfig::<Test> {
+		vesting_schedule: vec![],
+		vesting: vec![(BOB, 100)],
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+}
+
+#[test]
+fn vesting_info_should_work() {
+	ExtBuilder::default()
+		.existential_deposit(1)
+		.build()
+		.execute_with(|| {
+			assert_eq!(CalamariVesting::vesting_info(&BOB, 0, 0), None);
+
+			// Timestamps after the current time.
+			let t = VestingSchedule::<Test>::get()[0].1;
+
+			let steps = BoundedVec::try_from(vec![
+				(Percent::from_percent(40), t + 1_000),
+				(Percent::from_percent(60), t + 2_000),
+			])
+			.unwrap();
+			assert_ok!(CalamariVesting::force_vested_transfer(
+				Origin::root(),
+				ALICE,
+				BOB,
+				100,
+				Some(ScheduleKind::Steps(steps)),
+				false
+			));
+			assert_ok!(CalamariVesting::force_vested_transfer(
+				Origin::root(),
+				ALICE,
+				BOB,
+				100,
+				Some(ScheduleKind::Linear {
+					start: t + 1_000,
+					cliff: t + 1_500,
+					end: t + 2_000,
+				}),
+				false
+			));
+
+			assert_eq!(
+				CalamariVesting::vesting_info(&BOB, t + 999, 200),
+				Some(VestingInfo {
+					total: 200,
+					vested: 0,
+					locked: 200,
+					claimable: 0,
+					next_unlock: Some(t + 1_000),
+				})
+			);
+			// 40 of the steps and nothing of the linear grant before its cliff.
+			assert_eq!(
+				CalamariVesting::vesting_info(&BOB, t + 1_000, 200),
+				Some(VestingInfo {
+					total: 200,
+					vested: 40,
+					locked: 200,
+					claimable: 40,
+					next_unlock: Some(t + 1_500),
+				})
+			);
+			// Half of the linear grant unlocks at its cliff, then every second.
+			assert_eq!(
+				CalamariVesting::vesting_info(&BOB, t + 1_500, 160),
+				Some(VestingInfo {
+					total: 200,
+					vested: 90,
+					locked: 160,
+					claimable: 50,
+					next_unlock: Some(t + 1_501),
+				})
+			);
+			assert_eq!(
+				CalamariVesting::vesting_info(&BOB, t + 2_000, 110),
+				Some(VestingInfo {
+					total: 200,
+					vested: 200,
+					locked: 110,
+					claimable: 110,
+					next_unlock: None,
+				})
+			);
+		});
+}
+
+#[test]
+fn replace_vesting_schedule_should_work() {
+	ExtBuilder::default()
+		.existential_deposit(1)
+		.build()
+		.execute_with(|| {
+			// The first two tranches have unlocked.
+			let old_schedule = VestingSchedule::<Test>::get();
+			let now = old_schedule[1].1 + 1;
+			Timestamp::set_timestamp(now * 1000);
+
+			// Keep them, then unlock the remaining 44% in three tranches.
+			let new_schedule: StepsOf<Test> = BoundedVec::try_from(vec![
+				old_schedule[0],
+				old_schedule[1],
+				(Percent::from_percent(4), now + 100),
+				(Percent::from_percent(20), now + 200),
+				(Percent::from_percent(20), now + 300),
+			])
+			.unwrap();
+			assert_noop!(
+				CalamariVesting::replace_vesting_schedule(
+					Origin::signed(ALICE),
+					new_schedule.clone()
+				),
+				sp_runtime::traits::BadOrigin
+			);
+			assert_ok!(CalamariVesting::replace_vesting_schedule(
+				Origin::root(),
+				new_schedule.clone()
+			));
+			assert_eq!(VestingSchedule::<Test>::get(), new_schedule);
+			System::assert_last_event(MockEvent::CalamariVesting(
+				PalletEvent::VestingScheduleReplaced(new_schedule),
+			));
+
+			// New vested transfers follow the new schedule.
+			assert_ok!(CalamariVesting::vested_transfer(
+				Origin::signed(ALICE),
+				BOB,
+				100,
+				None
+			));
+			assert_eq!(
+				CalamariVesting::vesting_info(&BOB, now + 100, 0)
+					.unwrap()
+					.vested,
+				60
+			);
+		});
+}
+
+#[test]
+fn replace_vesting_schedule_should_keep_unlocked_tranches() {
+	ExtBuilder::default()
+		.existential_deposit(1)
+		.build()
+		.execute_with(|| {
+			let old_schedule = VestingSchedule::<Test>::get();
+			let now = old_schedule[1].1 + 1;
+			Timestamp::set_timestamp(now * 1000);
+
+			let replace = |steps: Vec<(Percent, Schedule)>| {
+				CalamariVesting::replace_vesting_schedule(
+					Origin::root(),
+					BoundedVec::try_from(steps).unwrap(),
+				)
+			};
+
+			// An unlocked tranche changes its percentage.
+			assert_noop!(
+				replace(vec![
+					(Percent::from_percent(40), old_schedule[0].1),
+					old_schedule[1],
+					(Percent::from_percent(49), now + 100),
+				]),
+				Error::<Test>::InvalidSchedule
+			);
+			// An unlocked tranche is dropped.
+			assert_noop!(
+				replace(vec![
+					old_schedule[0],
+					(Percent::from_percent(55), now + 100)
+				]),
+				Error::<Test>::InvalidSchedule
+			);
+			// A locked tranche would unlock in the past.
+			assert_noop!(
+				replace(vec![
+					old_schedule[0],
+					old_schedule[1],
+					(Percent::from_percent(44), now),
+				]),
+				Error::<Test>::InvalidSchedule
+			);
+			// The tranches do not unlock 100%.
+			assert_noop!(
+				replace(vec![
+					old_schedule[0],
+					old_schedule[1],
+					(Percent::from_percent(43), now + 100),
+				]),
+				Error::<Test>::InvalidSchedulePercentage
+			);
+			assert_noop!(
+				replace(vec![
+					old_schedule[0],
+					old_schedule[1],
+					(Percent::from_percent(45), now + 100),
+				]),
+				Error::<Test>::InvalidSchedulePercentage
+			);
+		});
+}
+
+#[test]
+fn vested_transfer_needs_a_default_schedule() {
+	ExtBuilder::default()
+		.existential_deposit(1)
+		.build()
+		.execute_with(|| {
+			// The default schedule is left to governance.
+			VestingSchedule::<Test>::put(StepsOf::<Test>::default());
+			assert_noop!(
+				CalamariVesting::vested_transfer(Origin::signed(ALICE), BOB, 100, None),
+				Error::<Test>::NoDefaultSchedule
+			);
+			assert_noop!(
+				CalamariVesting::force_vested_transfer(
+					Origin::root(),
+					ALICE,
+					BOB,
+					100,
+					Some(ScheduleKind::Default),
+					true
+				),
+				Error::<Test>::NoDefaultSchedule
+			);
+
+			let now = MockClock::now();
+			assert_ok!(CalamariVesting::replace_vesting_schedule(
+				Origin::root(),
+				BoundedVec::try_from(vec![(Percent::from_percent(100), now + 100)]).unwrap()
+			));
+			assert_ok!(CalamariVesting::vested_transfer(
+				Origin::signed(ALICE),
+				BOB,
+				100,
+				None
+			));
+
+			// A grant on a fully unlocked schedule would vest nothing.
+			Timestamp::set_timestamp((now + 100) * 1000);
+			assert_noop!(
+				CalamariVesting::vested_transfer(Origin::signed(ALICE), BOB, 100, None),
+				Error::<Test>::NoDefaultSchedule
+			);
+		});
+}
+
+#[test]
+fn sweep_should_update_locks_without_vest() {
+	ExtBuilder::default()
+		.existential_deposit(1)
+		.build()
+		.execute_with(|| {
+			const CHARLIE: AccountId = 3;
+
+			for who in [BOB, CHARLIE] {
+				assert_ok!(CalamariVesting::vested_transfer(
+					Origin::signed(ALICE),
+					who,
+					100,
+					None
+				));
+			}
+
+			// No tranche has unlocked yet.
+			System::reset_events();
+			CalamariVesting::on_initialize(2);
+			assert!(System::events().is_empty());
+			assert_eq!(CalamariVesting::sweep_cursor(), None);
+
+			let now = VestingSchedule::<Test>::get()[0].1 + 1;
+			Timestamp::set_timestamp(now * 1000);
+			CalamariVesting::on_initialize(3);
+			for who in [BOB, CHARLIE] {
+				System::assert_has_event(MockEvent::CalamariVesting(PalletEvent::VestingUpdated(
+					who, 55,
+				)));
+				assert_ok!(Balances::transfer(Origin::signed(who), ALICE, 45));
+			}
+			System::assert_last_event(MockEvent::CalamariVesting(
+				PalletEvent::VestingSweepCompleted,
+			));
+			assert_eq!(CalamariVesting::last_sweep(), now);
+			assert_eq!(CalamariVesting::sweep_cursor(), None);
+
+			// Nothing more to sweep until the next tranche.
+			System::reset_events();
+			CalamariVesting::on_initialize(4);
+			assert!(System::events().is_empty());
+		});
+}
+
+#[test]
+fn sweep_should_resume_from_cursor() {
+	// Two accounts per block.
+	let per_account = <() as WeightInfo>::sweep_vesting_account();
+	ExtBuilder::default()
+		.existential_deposit(1)
+		.sweep_weight_limit(2 * per_account)
+		.build()
+		.execute_with(|| {
+			let accounts = [BOB, 3, 4];
+			for who in accounts {
+				assert_ok!(CalamariVesting::vested_transfer(
+					Origin::signed(ALICE),
+					who,
+					100,
+					None
+				));
+			}
+
+			let now = VestingSchedule::<Test>::get()[0].1 + 1;
+			Timestamp::set_timestamp(now * 1000);
+			System::reset_events();
+			CalamariVesting::on_initialize(2);
+			let swept = |events: Vec<_>| {
+				events
+					.into_iter()
+					.filter(|record: &frame_system::EventRecord<MockEvent, _>| {
+						matches!(
+							record.event,
+							MockEvent::CalamariVesting(PalletEvent::VestingUpdated(_, 55))
+						)
+					})
+					.count()
+			};
+			assert_eq!(swept(System::events()), 2);
+			assert!(CalamariVesting::sweep_cursor().is_some());
+
+			// The sweep goes on even though no other tranche unlocks.
+			System::reset_events();
+			CalamariVesting::on_initialize(3);
+			assert_eq!(swept(System::events()), 1);
+			System::assert_last_event(MockEvent::CalamariVesting(
+				PalletEvent::VestingSweepCompleted,
+			));
+			assert_eq!(CalamariVesting::sweep_cursor(), None);
+			for who in accounts {
+				assert_ok!(Balances::transfer(Origin::signed(who), ALICE, 45));
+			}
+		});
+}
+
+#[test]
+fn sweep_should_follow_custom_schedules() {
+	ExtBuilder::default()
+		.existential_deposit(1)
+		.build()
+		.execute_with(|| {
+			const CHARLIE: AccountId = 3;
+
+			// Both custom schedules end before the first tranche of the default one.
+			let t = VestingSchedule::<Test>::get()[0].1 - 18;
+			let steps = BoundedVec::try_from(vec![
+				(Percent::from_percent(50), t + 2),
+				(Percent::from_percent(50), t + 4),
+			])
+			.unwrap();
+			assert_ok!(CalamariVesting::force_vested_transfer(
+				Origin::root(),
+				ALICE,
+				BOB,
+				100,
+				Some(ScheduleKind::Steps(steps)),
+				false
+			));
+			assert_ok!(CalamariVesting::force_vested_transfer(
+				Origin::root(),
+				ALICE,
+				CHARLIE,
+				100,
+				Some(ScheduleKind::Linear {
+					start: t,
+					cliff: t + 2,
+					end: t + 10,
+				}),
+				false
+			));
+			assert_eq!(CalamariVesting::next_grant_unlock(), Some(t + 2));
+
+			let sweep_at = |now: Schedule| {
+				Timestamp::set_timestamp(now * 1000);
+				System::reset_events();
+				CalamariVesting::on_initialize(2);
+			};
+
+			sweep_at(t + 1);
+			assert!(System::events().is_empty());
+
+			// The first step of Bob and the cliff of Charlie.
+			sweep_at(t + 2);
+			System::assert_has_event(MockEvent::CalamariVesting(PalletEvent::VestingUpdated(
+				BOB, 50,
+			)));
+			System::assert_has_event(MockEvent::CalamariVesting(PalletEvent::VestingUpdated(
+				CHARLIE, 80,
+			)));
+			assert_eq!(CalamariVesting::next_grant_unlock(), Some(t + 4));
+
+			// A linear schedule is not swept between its cliff and its end.
+			sweep_at(t + 3);
+			assert!(System::events().is_empty());
+
+			sweep_at(t + 4);
+			System::assert_has_event(MockEvent::CalamariVesting(PalletEvent::VestingCompleted(
+				BOB,
+			)));
+			System::assert_has_event(MockEvent::CalamariVesting(PalletEvent::VestingUpdated(
+				CHARLIE, 60,
+			)));
+			assert_eq!(CalamariVesting::next_grant_unlock(), Some(t + 10));
+
+			sweep_at(t + 10);
+			System::assert_has_event(MockEvent::CalamariVesting(PalletEvent::VestingCompleted(
+				CHARLIE,
+			)));
+			assert_eq!(CalamariVesting::next_grant_unlock(), None);
+			assert_eq!(CalamariVesting::vesting_balance(&CHARLIE), None);
+		});
+}
+
+#[test]
+fn vesting_clocks_should_work() {
+	ExtBuilder::default()
+		.existential_deposit(1)
+		.build()
+		.execute_with(|| {
+			Timestamp::set_timestamp(1_639_094_400_999);
+			assert_eq!(TimestampSeconds::<Timestamp>::now(), 1_639_094_400);
+
+			run_to_block(42);
+			assert_eq!(BlockNumberClock::<Test>::now(), 42);
+		});
+}
+
+#[test]
+fn vesting_by_block_number_should_work() {
+	ExtBuilder::default()
+		.existential_deposit(1)
+		.block_number_clock()
+		.vesting_schedule(vec![
+			(Percent::from_percent(45), 10),
+			(Percent::from_percent(55), 20),
+		])
+		.build()
+		.execute_with(|| {
+			assert_ok!(CalamariVesting::vested_transfer(
+				Origin::signed(ALICE),
+				BOB,
+				100,
+				None
+			));
+			assert_noop!(
+				CalamariVesting::vest(Origin::signed(BOB)),
+				Error::<Test>::ClaimTooEarly,
+			);
+
+			// The timestamp does not matter.
+			run_to_block(9);
+			Timestamp::set_timestamp(30_000);
+			assert_noop!(
+				CalamariVesting::vest(Origin::signed(BOB)),
+				Error::<Test>::ClaimTooEarly,
+			);
+
+			run_to_block(10);
+			assert_ok!(CalamariVesting::vest(Origin::signed(BOB)));
+			System::assert_last_event(MockEvent::CalamariVesting(PalletEvent::VestingUpdated(
+				BOB, 55,
+			)));
+			assert_noop!(
+				Balances::transfer(Origin::signed(BOB), ALICE, 46),
+				pallet_balances::Error::<Test, _>::LiquidityRestrictions,
+			);
+			assert_ok!(Balances::transfer(Origin::signed(BOB), ALICE, 45));
+
+			run_to_block(20);
+			assert_ok!(CalamariVesting::vest(Origin::signed(BOB)));
+			System::assert_last_event(MockEvent::CalamariVesting(PalletEvent::VestingCompleted(
+				BOB,
+			)));
+		});
+}
+
+#[test]
+fn update_vesting_schedule_by_block_number_should_work() {
+	ExtBuilder::default()
+		.existential_deposit(1)
+		.block_number_clock()
+		.vesting_schedule(vec![
+			(Percent::from_percent(45), 10),
+			(Percent::from_percent(55), 20),
+		])
+		.build()
+		.execute_with(|| {
+			let update = |new_schedule: Vec<Schedule>| {
+				CalamariVesting::update_vesting_schedule(
+					Origin::root(),
+					BoundedVec::try_from(new_schedule).unwrap(),
+				)
+			};
+
+			// The first tranche has unlocked.
+			run_to_block(15);
+			assert_noop!(update(vec![10, 5]), Error::<Test>::UnsortedSchedule);
+			assert_noop!(update(vec![11, 20]), Error::<Test>::InvalidSchedule);
+			// The second tranche would unlock in the past.
+			assert_noop!(update(vec![10, 14]), Error::<Test>::InvalidSchedule);
+
+			assert_ok!(update(vec![10, 30]));
+			assert_eq!(
+				VestingSchedule::<Test>::get().into_inner(),
+				vec![
+					(Percent::from_percent(45), 10),
+					(Percent::from_percent(55), 30),
+				]
+			);
+		});
+}
+
+#[test]
+fn batch_vested_transfer_should_work() {
+	ExtBuilder::default()
+		.existential_deposit(1)
+		.build()
+		.execute_with(|| {
+			assert_ok!(CalamariVesting::batch_vested_transfer(
+				Origin::signed(ALICE),
+				vec![(BOB, 100), (3, 200)]
+			));
+			assert_eq!(Balances::free_balance(ALICE), ALICE_DEPOSIT - 300);
+			assert_eq!(Balances::free_balance(BOB), 100);
+			assert_eq!(Balances::free_balance(3), 200);
+			assert_eq!(CalamariVesting::vesting_balance(&BOB), Some(100));
+			assert_eq!(CalamariVesting::vesting_balance(&3), Some(200));
+			System::assert_last_event(MockEvent::CalamariVesting(
+				PalletEvent::BatchVestedTransferCompleted(ALICE, vec![]),
+			));
+		});
+}
+
+#[test]
+fn batch_vested_transfer_should_skip_failed_transfers() {
+	ExtBuilder::default()
+		.existential_deposit(1)
+		.build()
+		.execute_with(|| {
+			// Bob already has the maximum number of grants.
+			for _ in 0..MaxVestingGrants::get() {
+				assert_ok!(CalamariVesting::vested_transfer(
+					Origin::signed(ALICE),
+					BOB,
+					100,
+					None
+				));
+			}
+
+			assert_ok!(CalamariVesting::batch_vested_transfer(
+				Origin::signed(ALICE),
+				vec![
+					(3, 100),
+					(BOB, 100),
+					(4, MinVestedTransfer::get() - 1),
+					(5, 100),
+				]
+			));
+			assert_eq!(Balances::free_balance(ALICE), ALICE_DEPOSIT - 500);
+			assert_eq!(CalamariVesting::vesting_grants(BOB).len(), 3);
+			assert_eq!(CalamariVesting::vesting_balance(&BOB), Some(300));
+			assert_eq!(Balances::free_balance(4), 0);
+			assert_eq!(CalamariVesting::vesting_balance(&3), Some(100));
+			assert_eq!(CalamariVesting::vesting_balance(&5), Some(100));
+			System::assert_last_event(MockEvent::CalamariVesting(
+				PalletEvent::BatchVestedTransferCompleted(ALICE, vec![1, 2]),
+			));
+		});
+}
+
+#[test]
+fn batch_vested_transfer_should_check_the_total() {
+	ExtBuilder::default()
+		.existential_deposit(1)
+		.build()
+		.execute_with(|| {
+			// Each transfer is affordable, but not all of them.
+			assert_noop!(
+				CalamariVesting::batch_vested_transfer(
+					Origin::signed(ALICE),
+					vec![(BOB, ALICE_DEPOSIT / 2), (3, ALICE_DEPOSIT / 2 + 1)]
+				),
+				Error::<Test>::BalanceLow
+			);
+
+			assert_noop!(
+				CalamariVesting::batch_vested_transfer(
+					Origin::signed(ALICE),
+					(0..=MaxBatchVestedTransfers::get() as AccountId)
+						.map(|i| (BOB + i, 100))
+						.collect()
+				),
+				Error::<Test>::TooManyVestedTransfers
+			);
+		});
+}
@@ -14,13 +14,13 @@
 // You should have received a copy of the GNU General Public License
 // along with Manta.  If not, see <http://www.gnu.org/licenses/>.
 
-//! Autogenerated weights for calamari_vesting
+//! Placeholder weights for calamari_vesting
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
-//! DATE: 2021-11-22, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("calamari-dev"), DB CACHE: 128
+//! THESE ARE NOT BENCHMARK RESULTS. They were estimated by hand from the storage accesses
+//! of each extrinsic, and must be regenerated on reference hardware with the command below
+//! before they are relied on.
 
-// Executed Command:
+// Command to regenerate:
 // manta
 // benchmark
 // --chain=calamari-dev
@@ -47,6 +47,13 @@ pub trait WeightInfo {
 	fn update_vesting_schedule() -> Weight;
 	fn vest() -> Weight;
 	fn vested_transfer() -> Weight;
+	fn merge_grants() -> Weight;
+	fn vest_other() -> Weight;
+	fn force_vested_transfer() -> Weight;
+	fn revoke_vesting() -> Weight;
+	fn replace_vesting_schedule() -> Weight;
+	fn sweep_vesting_account() -> Weight;
+	fn batch_vested_transfer(n: u32, ) -> Weight;
 }
 
 /// Weights for calamari_vesting using the Substrate node and recommended hardware.
@@ -59,26 +66,88 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2 as Weight))
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	// Storage: CalamariVesting VestingGrants (r:1 w:1)
 	// Storage: Timestamp Now (r:1 w:0)
-	// Storage: CalamariVesting VestingSchedule (r:1 w:0)
-	// Storage: CalamariVesting VestingBalances (r:1 w:1)
 	// Storage: Balances Locks (r:1 w:1)
 	// Storage: System Account (r:1 w:1)
 	fn vest() -> Weight {
 		(110_048_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
 			.saturating_add(T::DbWeight::get().writes(3 as Weight))
 	}
-	// Storage: CalamariVesting VestingBalances (r:1 w:1)
+	// Storage: CalamariVesting VestingGrants (r:1 w:1)
 	// Storage: System Account (r:1 w:1)
 	// Storage: Timestamp Now (r:1 w:0)
-	// Storage: CalamariVesting VestingSchedule (r:1 w:0)
 	// Storage: Balances Locks (r:1 w:1)
 	fn vested_transfer() -> Weight {
 		(216_897_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: CalamariVesting VestingGrants (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn merge_grants() -> Weight {
+		(78_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
 			.saturating_add(T::DbWeight::get().writes(3 as Weight))
 	}
+	// Storage: CalamariVesting VestingGrants (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn vest_other() -> Weight {
+		(112_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: System Account (r:2 w:2)
+	// Storage: CalamariVesting VestingGrants (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Balances Locks (r:1 w:1)
+	fn force_vested_transfer() -> Weight {
+		(220_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: CalamariVesting VestingGrants (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn revoke_vesting() -> Weight {
+		(150_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: CalamariVesting VestingSchedule (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	fn replace_vesting_schedule() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: CalamariVesting VestingGrants (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Balances Locks (r:1 w:1)
+	fn sweep_vesting_account() -> Weight {
+		(30_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: System Account (r:1 w:1)
+	// Storage: CalamariVesting VestingSchedule (r:1 w:0)
+	// Storage: CalamariVesting VestingGrants (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Balances Locks (r:1 w:1)
+	fn batch_vested_transfer(n: u32, ) -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add((61_000_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads((4 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(n as Weight)))
+	}
 }
 
 // For backwards compatibility and tests
@@ -90,24 +159,86 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
 	}
+	// Storage: CalamariVesting VestingGrants (r:1 w:1)
 	// Storage: Timestamp Now (r:1 w:0)
-	// Storage: CalamariVesting VestingSchedule (r:1 w:0)
-	// Storage: CalamariVesting VestingBalances (r:1 w:1)
 	// Storage: Balances Locks (r:1 w:1)
 	// Storage: System Account (r:1 w:1)
 	fn vest() -> Weight {
 		(110_048_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
 	}
-	// Storage: CalamariVesting VestingBalances (r:1 w:1)
+	// Storage: CalamariVesting VestingGrants (r:1 w:1)
 	// Storage: System Account (r:1 w:1)
 	// Storage: Timestamp Now (r:1 w:0)
-	// Storage: CalamariVesting VestingSchedule (r:1 w:0)
 	// Storage: Balances Locks (r:1 w:1)
 	fn vested_transfer() -> Weight {
 		(216_897_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: CalamariVesting VestingGrants (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn merge_grants() -> Weight {
+		(78_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
 	}
+	// Storage: CalamariVesting VestingGrants (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn vest_other() -> Weight {
+		(112_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: System Account (r:2 w:2)
+	// Storage: CalamariVesting VestingGrants (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Balances Locks (r:1 w:1)
+	fn force_vested_transfer() -> Weight {
+		(220_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	// Storage: CalamariVesting VestingGrants (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn revoke_vesting() -> Weight {
+		(150_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	// Storage: CalamariVesting VestingSchedule (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	fn replace_vesting_schedule() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: CalamariVesting VestingGrants (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Balances Locks (r:1 w:1)
+	fn sweep_vesting_account() -> Weight {
+		(30_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: System Account (r:1 w:1)
+	// Storage: CalamariVesting VestingSchedule (r:1 w:0)
+	// Storage: CalamariVesting VestingGrants (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Balances Locks (r:1 w:1)
+	fn batch_vested_transfer(n: u32, ) -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add((61_000_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((4 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((3 as Weight).saturating_mul(n as Weight)))
+	}
 }
@@ -14,8 +14,9 @@
 // You should have received a copy of the GNU General Public License
 // along with Manta.  If not, see <http://www.gnu.org/licenses/>.
 
-use crate::{Authorship, Balances, NegativeImbalance, Treasury};
+use crate::{Authorship, Balances, Call, NegativeImbalance, Runtime, Treasury};
 use frame_support::traits::{Currency, Imbalance, OnUnbalanced};
+use sp_runtime::DispatchResult;
 
 pub struct Author;
 impl OnUnbalanced<NegativeImbalance> for Author {
@@ -41,3 +42,176 @@ impl OnUnbalanced<NegativeImbalance> for DealWithFees {
 		}
 	}
 }
+
+/// The runtime `Call`, refusing to dispatch while it is strictly paused by `TransactionPause`.
+pub type GuardedCall = pallet_tx_pause::GuardedCall<Call, Runtime>;
+
+/// The calls nested in utility, sudo and multisig calls, which strict pauses also apply to.
+pub struct NestedCalls;
+impl pallet_tx_pause::NestedCalls<Call> for NestedCalls {
+	fn try_for_each_nested(
+		call: &Call,
+		f: &mut dyn FnMut(&Call) -> DispatchResult,
+	) -> DispatchResult {
+		match call {
+			Call::Utility(pallet_utility::Call::batch { calls })
+			| Call::Utility(pallet_utility::Call::batch_all { calls }) => {
+				calls.iter().try_for_each(|call| f(call))
+			}
+			Call::Utility(pallet_utility::Call::as_derivative { call, .. })
+			| Call::Multisig(pallet_multisig::Call::as_multi_threshold_1 { call, .. }) => f(call.as_ref()),
+			Call::Multisig(pallet_multisig::Call::as_multi { call, .. }) => {
+				// a call which does not decode cannot be dispatched either
+				call.try_decode().map_or(Ok(()), |call| f(&call))
+			}
+			Call::Sudo(pallet_sudo::Call::sudo { call })
+			| Call::Sudo(pallet_sudo::Call::sudo_unchecked_weight { call, .. })
+			| Call::Sudo(pallet_sudo::Call::sudo_as { call, .. }) => f(&call.0),
+			_ => Ok(()),
+		}
+	}
+}
+
+#[cfg(test)]
+mod guarded_call_tests {
+	use super::GuardedCall;
+	use crate::{Balances, Call, Origin, Runtime, Scheduler, System, TransactionPause, KMA};
+	use frame_support::{
+		assert_noop, assert_ok,
+		traits::{OnInitialize, UnfilteredDispatchable},
+	};
+	use manta_primitives::{AccountId, BlockNumber};
+
+	fn alice() -> AccountId {
+		AccountId::new([1u8; 32])
+	}
+
+	fn new_test_ext() -> sp_io::TestExternalities {
+		let mut ext: sp_io::TestExternalities = frame_system::GenesisConfig::default()
+			.build_storage::<Runtime>()
+			.unwrap()
+			.into();
+		ext.execute_with(|| System::set_block_number(1));
+		ext
+	}
+
+	fn set_balance(new_free: u128) -> Call {
+		Call::Balances(pallet_balances::Call::set_balance {
+			who: alice().into(),
+			new_free,
+			new_reserved: 0,
+		})
+	}
+
+	fn pause_set_balance(strict: bool) {
+		assert_ok!(TransactionPause::pause_transaction(
+			Origin::root(),
+			b"Balances".to_vec(),
+			b"set_balance".to_vec(),
+			None,
+			b"incident".to_vec()
+		));
+		assert_ok!(TransactionPause::set_strict_pause(
+			Origin::root(),
+			b"Balances".to_vec(),
+			b"set_balance".to_vec(),
+			strict
+		));
+	}
+
+	/// Schedule `call` as Root for the next block and run the scheduler in it.
+	fn run_scheduled(call: Call) {
+		let when: BlockNumber = System::block_number() + 1;
+		assert_ok!(Scheduler::schedule(
+			Origin::root(),
+			when,
+			None,
+			0,
+			Box::new(GuardedCall::new(call).into())
+		));
+		System::set_block_number(when);
+		Scheduler::on_initialize(when);
+	}
+
+	#[test]
+	fn scheduled_root_call_ignores_pause() {
+		new_test_ext().execute_with(|| {
+			pause_set_balance(false);
+
+			run_scheduled(set_balance(KMA));
+			assert_eq!(Balances::free_balance(&alice()), KMA);
+		});
+	}
+
+	#[test]
+	fn scheduled_root_call_is_refused_by_strict_pause() {
+		new_test_ext().execute_with(|| {
+			pause_set_balance(true);
+
+			run_scheduled(set_balance(KMA));
+			assert_eq!(Balances::free_balance(&alice()), 0);
+
+			// the refused call is dropped, lifting the strict pause does not dispatch it
+			assert_ok!(TransactionPause::set_strict_pause(
+				Origin::root(),
+				b"Balances".to_vec(),
+				b"set_balance".to_vec(),
+				false
+			));
+			let next = System::block_number() + 1;
+			System::set_block_number(next);
+			Scheduler::on_initialize(next);
+			assert_eq!(Balances::free_balance(&alice()), 0);
+
+			// it has to be scheduled again
+			run_scheduled(set_balance(KMA));
+			assert_eq!(Balances::free_balance(&alice()), KMA);
+		});
+	}
+
+	#[test]
+	fn strictly_paused_call_nested_in_root_batch_is_refused() {
+		new_test_ext().execute_with(|| {
+			pause_set_balance(true);
+
+			let batch = |calls| Call::Utility(pallet_utility::Call::batch { calls });
+			assert_noop!(
+				GuardedCall::new(batch(vec![set_balance(KMA)]))
+					.dispatch_bypass_filter(Origin::root()),
+				pallet_tx_pause::Error::<Runtime>::StrictlyPaused
+			);
+			// however deep it is nested
+			let sudo_as = Call::Sudo(pallet_sudo::Call::sudo_as {
+				who: alice().into(),
+				call: Box::new(GuardedCall::new(batch(vec![set_balance(KMA)]))),
+			});
+			assert_noop!(
+				GuardedCall::new(Call::Utility(pallet_utility::Call::batch_all {
+					calls: vec![sudo_as]
+				}))
+				.dispatch_bypass_filter(Origin::root()),
+				pallet_tx_pause::Error::<Runtime>::StrictlyPaused
+			);
+
+			run_scheduled(batch(vec![set_balance(KMA)]));
+			assert_eq!(Balances::free_balance(&alice()), 0);
+		});
+	}
+
+	#[test]
+	fn force_dispatch_paused_overrides_strict_pause() {
+		new_test_ext().execute_with(|| {
+			pause_set_balance(true);
+
+			assert_noop!(
+				GuardedCall::new(set_balance(KMA)).dispatch_bypass_filter(Origin::root()),
+				pallet_tx_pause::Error::<Runtime>::StrictlyPaused
+			);
+			assert_ok!(TransactionPause::force_dispatch_paused(
+				Origin::root(),
+				Box::new(set_balance(KMA))
+			));
+			assert_eq!(Balances::free_balance(&alice()), KMA);
+		});
+	}
+}
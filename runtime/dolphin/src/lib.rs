@@ -40,7 +40,7 @@ use sp_version::RuntimeVersion;
 
 use frame_support::{
 	construct_runtime, match_type, parameter_types,
-	traits::{Contains, Currency, EnsureOneOf, Everything, Nothing, PrivilegeCmp},
+	traits::{Contains, Currency, EnsureOneOf, Everything, LockIdentifier, Nothing, PrivilegeCmp},
 	weights::{
 		constants::{BlockExecutionWeight, ExtrinsicBaseWeight, WEIGHT_PER_SECOND},
 		DispatchClass, IdentityFee, Weight,
@@ -54,7 +54,7 @@ use frame_system::{
 use manta_primitives::{
 	time::*, AccountId, AuraId, Balance, BlockNumber, Hash, Header, Index, Signature,
 };
-use sp_runtime::{Perbill, Permill};
+use sp_runtime::{Perbill, Percent, Permill};
 
 #[cfg(any(feature = "std", test))]
 pub use sp_runtime::BuildStorage;
@@ -79,7 +79,7 @@ pub mod impls;
 
 use currency::*;
 use fee::WeightToFee;
-use impls::DealWithFees;
+use impls::{DealWithFees, GuardedCall, NestedCalls};
 
 pub type NegativeImbalance = <Balances as Currency<AccountId>>::NegativeImbalance;
 
@@ -114,7 +114,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_version: 3120,
 	impl_version: 1,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	transaction_version: 4,
 	state_version: 0,
 };
 
@@ -162,12 +162,59 @@ parameter_types! {
 	pub const SS58Prefix: u8 = manta_primitives::constants::CALAMARI_SS58PREFIX;
 }
 
+parameter_types! {
+	pub MaxExpiryWeight: Weight = Perbill::from_percent(2) * RuntimeBlockWeights::get().max_block;
+	pub const MaxNameLen: u32 = 64;
+	pub const MaxReasonLen: u32 = 256;
+	pub const MaxPauseBatch: u32 = 32;
+}
+
+type EnsureRootOrMoreThanHalfTechnicalCommittee = EnsureOneOf<
+	EnsureRoot<AccountId>,
+	pallet_collective::EnsureProportionMoreThan<_1, _2, AccountId, TechnicalCollective>,
+>;
+
 impl pallet_tx_pause::Config for Runtime {
 	type Event = Event;
-	type UpdateOrigin = EnsureRoot<AccountId>;
+	type PauseOrigin = EnsureRootOrMoreThanHalfTechnicalCommittee;
+	type UnpauseOrigin = EnsureRootOrThreeFourthsCouncil;
+	type ForceDispatchOrigin = EnsureRoot<AccountId>;
+	type HaltAllowList = HaltAllowList;
+	type KnownCalls = Call;
+	type NestedCalls = NestedCalls;
+	type PalletsInfo = AllPalletsWithSystem;
+	type MaxExpiryWeight = MaxExpiryWeight;
+	type MaxNameLen = MaxNameLen;
+	type MaxReasonLen = MaxReasonLen;
+	type MaxPauseBatch = MaxPauseBatch;
 	type WeightInfo = weights::pallet_tx_pause::SubstrateWeight<Runtime>;
 }
 
+impl pallet_account_freeze::Config for Runtime {
+	type Event = Event;
+	type FreezeOrigin = EnsureRootOrMoreThanHalfTechnicalCommittee;
+	// A frozen account is unfrozen by governance, it may not sign any call meanwhile.
+	type RecoveryCalls = Nothing;
+	type DispatchAccounts = pallet_account_freeze::MultisigAndUtilityAccounts<Runtime>;
+	type WeightInfo = weights::pallet_account_freeze::SubstrateWeight<Runtime>;
+}
+
+/// Calls which stay available during an emergency halt, so that it can be handled and lifted.
+pub struct HaltAllowList;
+impl Contains<Call> for HaltAllowList {
+	fn contains(call: &Call) -> bool {
+		matches!(
+			call,
+			Call::Sudo(_)
+				| Call::Democracy(_)
+				| Call::Council(_)
+				| Call::TechnicalCommittee(_)
+				| Call::TransactionPause(_)
+				| Call::AccountFreeze(_)
+		)
+	}
+}
+
 // Don't allow permission-less asset creation.
 pub struct BaseFilter;
 impl Contains<Call> for BaseFilter {
@@ -181,6 +228,11 @@ impl Contains<Call> for BaseFilter {
 			return true;
 		}
 
+		if pallet_tx_pause::EmergencyHaltFilter::<Runtime>::contains(call) {
+			// only allow-listed calls during an emergency halt
+			return false;
+		}
+
 		if pallet_tx_pause::PausedTransactionFilter::<Runtime>::contains(call) {
 			// no paused call
 			return false;
@@ -223,9 +275,13 @@ impl Contains<Call> for BaseFilter {
 			| Call::TechnicalCommittee(_)
 			| Call::CouncilMembership(_)
 			| Call::TechnicalMembership(_)
+			// Pauses are proposed through the collectives, whose origins are filtered.
+			| Call::TransactionPause(_)
+			| Call::AccountFreeze(_)
 			// Treasury calls are filtered while it is accumulating funds.
 			//| Call::Treasury(_)
 			| Call::Scheduler(_)
+			| Call::CalamariVesting(_)
 			// We open pallet-session because user has to set his own session keys before register as collator.
 			| Call::Session(_)
 			// Currently, We filter `register_as_candidate` due to this call is not ready for community.
@@ -355,7 +411,7 @@ impl pallet_utility::Config for Runtime {
 
 impl pallet_sudo::Config for Runtime {
 	type Event = Event;
-	type Call = Call;
+	type Call = GuardedCall;
 }
 
 parameter_types! {
@@ -372,7 +428,7 @@ parameter_types! {
 }
 
 impl pallet_democracy::Config for Runtime {
-	type Proposal = Call;
+	type Proposal = GuardedCall;
 	type Event = Event;
 	type Currency = Balances;
 	type EnactmentPeriod = EnactmentPeriod;
@@ -565,7 +621,7 @@ impl pallet_scheduler::Config for Runtime {
 	type Event = Event;
 	type Origin = Origin;
 	type PalletsOrigin = OriginCaller;
-	type Call = Call;
+	type Call = GuardedCall;
 	type MaximumWeight = MaximumSchedulerWeight;
 	type ScheduleOrigin = ScheduleOrigin;
 	type MaxScheduledPerBlock = MaxScheduledPerBlock;
@@ -835,6 +891,37 @@ impl manta_collator_selection::Config for Runtime {
 	type WeightInfo = manta_collator_selection::weights::SubstrateWeight<Runtime>;
 }
 
+// Dolphin pallets configuration
+parameter_types! {
+	pub const MinVestedTransfer: Balance = DOL;
+	pub const MinCustomVestedTransfer: Balance = 10 * DOL;
+	pub const MaxScheduleLength: u32 = 6;
+	pub const MaxVestingGrants: u32 = 10;
+	pub const VestingId: LockIdentifier = *b"dolphvst";
+	// Set by governance with `replace_vesting_schedule`, relative to when vesting starts.
+	pub DefaultVestingSchedule: Vec<(Percent, calamari_vesting::Schedule)> = vec![];
+	pub SweepWeightLimit: Weight = Perbill::from_percent(10) * MAXIMUM_BLOCK_WEIGHT;
+	pub const MaxBatchVestedTransfers: u32 = 100;
+}
+
+impl calamari_vesting::Config for Runtime {
+	type Currency = Balances;
+	type Event = Event;
+	// Test networks run on compressed timelines, so Dolphin vests by block number.
+	type Clock = calamari_vesting::BlockNumberClock<Runtime>;
+	type VestingId = VestingId;
+	type DefaultVestingSchedule = DefaultVestingSchedule;
+	type MinVestedTransfer = MinVestedTransfer;
+	type MinCustomVestedTransfer = MinCustomVestedTransfer;
+	type MaxScheduleLength = MaxScheduleLength;
+	type MaxVestingGrants = MaxVestingGrants;
+	type ForceOrigin = EnsureRoot<AccountId>;
+	type ScheduleOrigin = EnsureRootOrThreeFifthsCouncil;
+	type SweepWeightLimit = SweepWeightLimit;
+	type MaxBatchVestedTransfers = MaxBatchVestedTransfers;
+	type WeightInfo = weights::calamari_vesting::SubstrateWeight<Runtime>;
+}
+
 // Create the runtime by composing the FRAME pallets that were previously configured.
 construct_runtime!(
 	pub enum Runtime where
@@ -849,6 +936,7 @@ construct_runtime!(
 		} = 1,
 		Timestamp: pallet_timestamp::{Pallet, Call, Storage, Inherent} = 2,
 		ParachainInfo: parachain_info::{Pallet, Storage, Config} = 3,
+		AccountFreeze: pallet_account_freeze::{Pallet, Call, Storage, Event<T>} = 8,
 		TransactionPause: pallet_tx_pause::{Pallet, Call, Storage, Event<T>} = 9,
 
 		// Monetary stuff.
@@ -887,6 +975,9 @@ construct_runtime!(
 		Utility: pallet_utility::{Pallet, Call, Event} = 40,
 		Multisig: pallet_multisig::{Pallet, Call, Storage, Event<T>} = 41,
 		Sudo: pallet_sudo::{Pallet, Call, Config<T>, Storage, Event<T>} = 42,
+
+		// Dolphin stuff
+		CalamariVesting: calamari_vesting::{Pallet, Call, Storage, Event<T>} = 50,
 	}
 );
 
@@ -906,6 +997,8 @@ pub type SignedExtra = (
 	frame_system::CheckEra<Runtime>,
 	frame_system::CheckNonce<Runtime>,
 	frame_system::CheckWeight<Runtime>,
+	pallet_tx_pause::CheckTxPause<Runtime>,
+	pallet_account_freeze::CheckAccountFreeze<Runtime>,
 	pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
 );
 /// Unchecked extrinsic type as expected by this runtime.
@@ -919,6 +1012,7 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsReversedWithSystemFirst,
+	pallet_tx_pause::migrations::ReconcilePauseEntries<Runtime>,
 >;
 
 impl_runtime_apis! {
@@ -1022,6 +1116,21 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_tx_pause_rpc_runtime_api::TransactionPauseApi<Block> for Runtime {
+		fn paused_transactions() -> Vec<(Vec<u8>, Vec<u8>, pallet_tx_pause_rpc_runtime_api::PauseStatus)> {
+			TransactionPause::pause_entries()
+		}
+
+		fn call_filter_status(call: Vec<u8>) -> pallet_tx_pause_rpc_runtime_api::CallFilterStatus {
+			use pallet_tx_pause_rpc_runtime_api::CallFilterStatus;
+			match <Call as codec::Decode>::decode(&mut &call[..]) {
+				Ok(call) if BaseFilter::contains(&call) => TransactionPause::throttle_status(&call).unwrap_or(CallFilterStatus::Allowed),
+				Ok(call) => TransactionPause::pause_status(&call).unwrap_or(CallFilterStatus::NotAllowed),
+				Err(_) => CallFilterStatus::InvalidCall,
+			}
+		}
+	}
+
 	impl cumulus_primitives_core::CollectCollationInfo<Block> for Runtime {
 		fn collect_collation_info(header: &<Block as BlockT>::Header) -> cumulus_primitives_core::CollationInfo {
 			ParachainSystem::collect_collation_info(header)
@@ -1067,6 +1176,8 @@ impl_runtime_apis! {
 			list_benchmark!(list, extra, pallet_scheduler, Scheduler);
 			list_benchmark!(list, extra, pallet_session, SessionBench::<Runtime>);
 			list_benchmark!(list, extra, pallet_tx_pause, TransactionPause);
+			list_benchmark!(list, extra, calamari_vesting, CalamariVesting);
+			list_benchmark!(list, extra, pallet_account_freeze, AccountFreeze);
 
 			let storage_info = AllPalletsWithSystem::storage_info();
 
@@ -1115,6 +1226,8 @@ impl_runtime_apis! {
 			add_benchmark!(params, batches, pallet_treasury, Treasury);
 			add_benchmark!(params, batches, pallet_session, SessionBench::<Runtime>);
 			add_benchmark!(params, batches, pallet_tx_pause, TransactionPause);
+			add_benchmark!(params, batches, calamari_vesting, CalamariVesting);
+			add_benchmark!(params, batches, pallet_account_freeze, AccountFreeze);
 
 			if batches.is_empty() { return Err("Benchmark not found for this pallet.".into()) }
 			Ok(batches)
@@ -14,13 +14,13 @@
 // You should have received a copy of the GNU General Public License
 // along with Manta.  If not, see <http://www.gnu.org/licenses/>.
 
-//! Autogenerated weights for pallet_tx_pause
+//! Placeholder weights for pallet_tx_pause
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
-//! DATE: 2022-01-06, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dolphin-dev"), DB CACHE: 128
+//! THESE ARE NOT BENCHMARK RESULTS. They were estimated by hand from the storage accesses
+//! of each extrinsic, and must be regenerated on reference hardware with the command below
+//! before they are relied on.
 
-// Executed Command:
+// Command to regenerate:
 // manta
 // benchmark
 // --chain=dolphin-dev
@@ -45,37 +45,230 @@ use sp_std::marker::PhantomData;
 pub trait WeightInfo {
 	fn pause_transaction() -> Weight;
 	fn unpause_transaction() -> Weight;
+	fn pause_pallet() -> Weight;
+	fn unpause_pallet() -> Weight;
+	fn on_initialize(n: u32, ) -> Weight;
+	fn pause_transactions(n: u32, ) -> Weight;
+	fn unpause_transactions(n: u32, ) -> Weight;
+	fn halt() -> Weight;
+	fn resume() -> Weight;
+	fn throttle_transaction() -> Weight;
+	fn unthrottle_transaction() -> Weight;
+	fn pause_call() -> Weight;
+	fn unpause_call() -> Weight;
+	fn set_strict_pause() -> Weight;
+	fn force_dispatch_paused() -> Weight;
 }
 
 /// Weights for pallet_tx_pause using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> pallet_tx_pause::WeightInfo for SubstrateWeight<T> {
 	// Storage: TransactionPause PausedTransactions (r:1 w:1)
+	// Storage: TransactionPause PauseExpiries (r:1 w:1)
+	// Storage: TransactionPause PauseExpiryQueue (r:1 w:1)
 	fn pause_transaction() -> Weight {
 		(16_296_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(1 as Weight))
-			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
 	}
 	// Storage: TransactionPause PausedTransactions (r:1 w:1)
+	// Storage: TransactionPause PauseExpiries (r:0 w:1)
 	fn unpause_transaction() -> Weight {
 		(17_439_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: TransactionPause PausedPallets (r:1 w:1)
+	fn pause_pallet() -> Weight {
+		(16_502_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TransactionPause PausedPallets (r:1 w:1)
+	fn unpause_pallet() -> Weight {
+		(17_302_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TransactionPause ExpiryCursor (r:1 w:0)
+	// Storage: TransactionPause PauseExpiryQueue (r:1 w:1)
+	// Storage: TransactionPause PauseExpiries (r:1 w:1)
+	// Storage: TransactionPause PausedTransactions (r:0 w:1)
+	fn on_initialize(n: u32, ) -> Weight {
+		(4_694_000 as Weight)
+			.saturating_add((25_784_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(n as Weight)))
+	}
+	// Storage: TransactionPause PausedTransactions (r:1 w:1)
+	// Storage: TransactionPause PauseExpiries (r:1 w:1)
+	fn pause_transactions(n: u32, ) -> Weight {
+		(5_050_000 as Weight)
+			.saturating_add((15_484_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(n as Weight)))
+	}
+	// Storage: TransactionPause PausedTransactions (r:1 w:1)
+	// Storage: TransactionPause PauseExpiries (r:0 w:1)
+	fn unpause_transactions(n: u32, ) -> Weight {
+		(5_533_000 as Weight)
+			.saturating_add((16_111_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(n as Weight)))
+	}
+	// Storage: TransactionPause EmergencyHalt (r:1 w:1)
+	fn halt() -> Weight {
+		(17_182_000 as Weight)
 			.saturating_add(T::DbWeight::get().reads(1 as Weight))
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	// Storage: TransactionPause EmergencyHalt (r:1 w:1)
+	fn resume() -> Weight {
+		(17_642_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TransactionPause ThrottledTransactions (r:0 w:1)
+	fn throttle_transaction() -> Weight {
+		(18_127_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TransactionPause ThrottledTransactions (r:1 w:1)
+	fn unthrottle_transaction() -> Weight {
+		(18_626_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TransactionPause PausedCallIndices (r:1 w:1)
+	// Storage: TransactionPause PausedCallIndexCount (r:1 w:1)
+	fn pause_call() -> Weight {
+		(19_577_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: TransactionPause PausedCallIndices (r:1 w:1)
+	// Storage: TransactionPause PausedCallIndexCount (r:1 w:1)
+	fn unpause_call() -> Weight {
+		(19_050_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: TransactionPause PausedTransactions (r:1 w:1)
+	fn set_strict_pause() -> Weight {
+		(20_644_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TransactionPause PausedTransactions (r:1 w:0)
+	fn force_dispatch_paused() -> Weight {
+		(16_274_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+	}
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
 	// Storage: TransactionPause PausedTransactions (r:1 w:1)
+	// Storage: TransactionPause PauseExpiries (r:1 w:1)
+	// Storage: TransactionPause PauseExpiryQueue (r:1 w:1)
 	fn pause_transaction() -> Weight {
 		(16_296_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
 	}
 	// Storage: TransactionPause PausedTransactions (r:1 w:1)
+	// Storage: TransactionPause PauseExpiries (r:0 w:1)
 	fn unpause_transaction() -> Weight {
 		(17_439_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: TransactionPause PausedPallets (r:1 w:1)
+	fn pause_pallet() -> Weight {
+		(16_502_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TransactionPause PausedPallets (r:1 w:1)
+	fn unpause_pallet() -> Weight {
+		(17_302_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TransactionPause ExpiryCursor (r:1 w:0)
+	// Storage: TransactionPause PauseExpiryQueue (r:1 w:1)
+	// Storage: TransactionPause PauseExpiries (r:1 w:1)
+	// Storage: TransactionPause PausedTransactions (r:0 w:1)
+	fn on_initialize(n: u32, ) -> Weight {
+		(4_694_000 as Weight)
+			.saturating_add((25_784_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((2 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((3 as Weight).saturating_mul(n as Weight)))
+	}
+	// Storage: TransactionPause PausedTransactions (r:1 w:1)
+	// Storage: TransactionPause PauseExpiries (r:1 w:1)
+	fn pause_transactions(n: u32, ) -> Weight {
+		(5_050_000 as Weight)
+			.saturating_add((15_484_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads((2 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(n as Weight)))
+	}
+	// Storage: TransactionPause PausedTransactions (r:1 w:1)
+	// Storage: TransactionPause PauseExpiries (r:0 w:1)
+	fn unpause_transactions(n: u32, ) -> Weight {
+		(5_533_000 as Weight)
+			.saturating_add((16_111_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(n as Weight)))
+	}
+	// Storage: TransactionPause EmergencyHalt (r:1 w:1)
+	fn halt() -> Weight {
+		(17_182_000 as Weight)
 			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
 	}
+	// Storage: TransactionPause EmergencyHalt (r:1 w:1)
+	fn resume() -> Weight {
+		(17_642_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TransactionPause ThrottledTransactions (r:0 w:1)
+	fn throttle_transaction() -> Weight {
+		(18_127_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TransactionPause ThrottledTransactions (r:1 w:1)
+	fn unthrottle_transaction() -> Weight {
+		(18_626_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TransactionPause PausedCallIndices (r:1 w:1)
+	// Storage: TransactionPause PausedCallIndexCount (r:1 w:1)
+	fn pause_call() -> Weight {
+		(19_577_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: TransactionPause PausedCallIndices (r:1 w:1)
+	// Storage: TransactionPause PausedCallIndexCount (r:1 w:1)
+	fn unpause_call() -> Weight {
+		(19_050_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: TransactionPause PausedTransactions (r:1 w:1)
+	fn set_strict_pause() -> Weight {
+		(20_644_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TransactionPause PausedTransactions (r:1 w:0)
+	fn force_dispatch_paused() -> Weight {
+		(16_274_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+	}
 }
\ No newline at end of file
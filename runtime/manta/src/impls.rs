@@ -14,8 +14,9 @@
 // You should have received a copy of the GNU General Public License
 // along with Manta.  If not, see <http://www.gnu.org/licenses/>.
 
-use crate::{Authorship, Balances, NegativeImbalance};
+use crate::{Authorship, Balances, Call, NegativeImbalance, Runtime};
 use frame_support::traits::{Currency, Imbalance, OnUnbalanced};
+use sp_runtime::DispatchResult;
 
 pub struct Author;
 impl OnUnbalanced<NegativeImbalance> for Author {
@@ -39,3 +40,32 @@ impl OnUnbalanced<NegativeImbalance> for DealWithFees {
 		}
 	}
 }
+
+/// The runtime `Call`, refusing to dispatch while it is strictly paused by `TransactionPause`.
+pub type GuardedCall = pallet_tx_pause::GuardedCall<Call, Runtime>;
+
+/// The calls nested in utility, sudo and multisig calls, which strict pauses also apply to.
+pub struct NestedCalls;
+impl pallet_tx_pause::NestedCalls<Call> for NestedCalls {
+	fn try_for_each_nested(
+		call: &Call,
+		f: &mut dyn FnMut(&Call) -> DispatchResult,
+	) -> DispatchResult {
+		match call {
+			Call::Utility(pallet_utility::Call::batch { calls })
+			| Call::Utility(pallet_utility::Call::batch_all { calls }) => {
+				calls.iter().try_for_each(|call| f(call))
+			}
+			Call::Utility(pallet_utility::Call::as_derivative { call, .. })
+			| Call::Multisig(pallet_multisig::Call::as_multi_threshold_1 { call, .. }) => f(call.as_ref()),
+			Call::Multisig(pallet_multisig::Call::as_multi { call, .. }) => {
+				// a call which does not decode cannot be dispatched either
+				call.try_decode().map_or(Ok(()), |call| f(&call))
+			}
+			Call::Sudo(pallet_sudo::Call::sudo { call })
+			| Call::Sudo(pallet_sudo::Call::sudo_unchecked_weight { call, .. })
+			| Call::Sudo(pallet_sudo::Call::sudo_as { call, .. }) => f(&call.0),
+			_ => Ok(()),
+		}
+	}
+}
@@ -39,7 +39,7 @@ use sp_version::RuntimeVersion;
 
 use frame_support::{
 	construct_runtime, match_type, parameter_types,
-	traits::{Contains, Currency, EnsureOneOf, Everything, Nothing},
+	traits::{Contains, Currency, EnsureOneOf, Everything, LockIdentifier, Nothing},
 	weights::{
 		constants::{BlockExecutionWeight, ExtrinsicBaseWeight, WEIGHT_PER_SECOND},
 		DispatchClass, IdentityFee, Weight,
@@ -53,7 +53,7 @@ use frame_system::{
 use manta_primitives::{
 	time::*, AccountId, AuraId, Balance, BlockNumber, Hash, Header, Index, Signature,
 };
-use sp_runtime::Perbill;
+use sp_runtime::{Perbill, Percent};
 
 #[cfg(any(feature = "std", test))]
 pub use sp_runtime::BuildStorage;
@@ -78,7 +78,7 @@ pub mod impls;
 
 use currency::*;
 use fee::WeightToFee;
-use impls::DealWithFees;
+use impls::{DealWithFees, GuardedCall, NestedCalls};
 
 pub type NegativeImbalance = <Balances as Currency<AccountId>>::NegativeImbalance;
 
@@ -113,7 +113,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_version: 3140,
 	impl_version: 1,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	transaction_version: 4,
 	state_version: 0,
 };
 
@@ -161,12 +161,49 @@ parameter_types! {
 	pub const SS58Prefix: u8 = manta_primitives::constants::MANTA_SS58PREFIX;
 }
 
+parameter_types! {
+	pub MaxExpiryWeight: Weight = Perbill::from_percent(2) * RuntimeBlockWeights::get().max_block;
+	pub const MaxNameLen: u32 = 64;
+	pub const MaxReasonLen: u32 = 256;
+	pub const MaxPauseBatch: u32 = 32;
+}
+
 impl pallet_tx_pause::Config for Runtime {
 	type Event = Event;
-	type UpdateOrigin = EnsureRoot<AccountId>;
+	type PauseOrigin = EnsureRoot<AccountId>;
+	type UnpauseOrigin = EnsureRoot<AccountId>;
+	type ForceDispatchOrigin = EnsureRoot<AccountId>;
+	type HaltAllowList = HaltAllowList;
+	type KnownCalls = Call;
+	type NestedCalls = NestedCalls;
+	type PalletsInfo = AllPalletsWithSystem;
+	type MaxExpiryWeight = MaxExpiryWeight;
+	type MaxNameLen = MaxNameLen;
+	type MaxReasonLen = MaxReasonLen;
+	type MaxPauseBatch = MaxPauseBatch;
 	type WeightInfo = weights::pallet_tx_pause::SubstrateWeight<Runtime>;
 }
 
+impl pallet_account_freeze::Config for Runtime {
+	type Event = Event;
+	type FreezeOrigin = EnsureRoot<AccountId>;
+	// A frozen account is unfrozen by governance, it may not sign any call meanwhile.
+	type RecoveryCalls = Nothing;
+	type DispatchAccounts = pallet_account_freeze::MultisigAndUtilityAccounts<Runtime>;
+	type WeightInfo = weights::pallet_account_freeze::SubstrateWeight<Runtime>;
+}
+
+/// Calls which stay available during an emergency halt, so that it can be handled and lifted.
+pub struct HaltAllowList;
+impl Contains<Call> for HaltAllowList {
+	fn contains(call: &Call) -> bool {
+		matches!(
+			call,
+			Call::Sudo(_) | Call::TransactionPause(_) | Call::AccountFreeze(_)
+		)
+	}
+}
+
 // Don't allow permission-less asset creation.
 pub struct MantaFilter;
 impl Contains<Call> for MantaFilter {
@@ -180,13 +217,22 @@ impl Contains<Call> for MantaFilter {
 			return true;
 		}
 
+		if pallet_tx_pause::EmergencyHaltFilter::<Runtime>::contains(call) {
+			// only allow-listed calls during an emergency halt
+			return false;
+		}
+
 		if pallet_tx_pause::PausedTransactionFilter::<Runtime>::contains(call) {
 			// no paused call
 			return false;
 		}
 
 		match call {
-			Call::Authorship(_) | Call::Sudo(_) | Call::Multisig(_) | Call::Balances(_) => true,
+			Call::Authorship(_)
+			| Call::Sudo(_)
+			| Call::Multisig(_)
+			| Call::Balances(_)
+			| Call::CalamariVesting(_) => true,
 			// Sudo also cannot be filtered because it is used in runtime upgrade.
 			_ => false,
 			// Filter Utility to prevent users from setting keys and selecting collator for parachain (couldn't use now).
@@ -306,7 +352,7 @@ impl pallet_utility::Config for Runtime {
 
 impl pallet_sudo::Config for Runtime {
 	type Event = Event;
-	type Call = Call;
+	type Call = GuardedCall;
 }
 
 parameter_types! {
@@ -325,7 +371,7 @@ impl pallet_scheduler::Config for Runtime {
 	type Event = Event;
 	type Origin = Origin;
 	type PalletsOrigin = OriginCaller;
-	type Call = Call;
+	type Call = GuardedCall;
 	type MaximumWeight = MaximumSchedulerWeight;
 	type ScheduleOrigin = ScheduleOrigin;
 	type MaxScheduledPerBlock = MaxScheduledPerBlock;
@@ -592,6 +638,36 @@ impl pallet_collator_selection::Config for Runtime {
 	type WeightInfo = pallet_collator_selection::weights::SubstrateWeight<Runtime>;
 }
 
+// Manta pallets configuration
+parameter_types! {
+	pub const MinVestedTransfer: Balance = MANTA;
+	pub const MinCustomVestedTransfer: Balance = 10 * MANTA;
+	pub const MaxScheduleLength: u32 = 6;
+	pub const MaxVestingGrants: u32 = 10;
+	pub const VestingId: LockIdentifier = *b"mantavst";
+	// Set by governance with `replace_vesting_schedule`, relative to when vesting starts.
+	pub DefaultVestingSchedule: Vec<(Percent, calamari_vesting::Schedule)> = vec![];
+	pub SweepWeightLimit: Weight = Perbill::from_percent(10) * MAXIMUM_BLOCK_WEIGHT;
+	pub const MaxBatchVestedTransfers: u32 = 100;
+}
+
+impl calamari_vesting::Config for Runtime {
+	type Currency = Balances;
+	type Event = Event;
+	type Clock = calamari_vesting::TimestampSeconds<Timestamp>;
+	type VestingId = VestingId;
+	type DefaultVestingSchedule = DefaultVestingSchedule;
+	type MinVestedTransfer = MinVestedTransfer;
+	type MinCustomVestedTransfer = MinCustomVestedTransfer;
+	type MaxScheduleLength = MaxScheduleLength;
+	type MaxVestingGrants = MaxVestingGrants;
+	type ForceOrigin = EnsureRoot<AccountId>;
+	type ScheduleOrigin = EnsureRoot<AccountId>;
+	type SweepWeightLimit = SweepWeightLimit;
+	type MaxBatchVestedTransfers = MaxBatchVestedTransfers;
+	type WeightInfo = weights::calamari_vesting::SubstrateWeight<Runtime>;
+}
+
 // Create the runtime by composing the FRAME pallets that were previously configured.
 construct_runtime!(
 	pub enum Runtime where
@@ -606,6 +682,7 @@ construct_runtime!(
 		} = 1,
 		Timestamp: pallet_timestamp::{Pallet, Call, Storage, Inherent} = 2,
 		ParachainInfo: parachain_info::{Pallet, Storage, Config} = 3,
+		AccountFreeze: pallet_account_freeze::{Pallet, Call, Storage, Event<T>} = 8,
 		TransactionPause: pallet_tx_pause::{Pallet, Call, Storage, Event<T>} = 9,
 		// Monetary stuff.
 		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>} = 10,
@@ -634,6 +711,9 @@ construct_runtime!(
 		Multisig: pallet_multisig::{Pallet, Call, Storage, Event<T>} = 41,
 		// Temporary
 		Sudo: pallet_sudo::{Pallet, Call, Config<T>, Storage, Event<T>} = 42,
+
+		// Manta stuff
+		CalamariVesting: calamari_vesting::{Pallet, Call, Storage, Event<T>} = 50,
 	}
 );
 
@@ -653,6 +733,8 @@ pub type SignedExtra = (
 	frame_system::CheckEra<Runtime>,
 	frame_system::CheckNonce<Runtime>,
 	frame_system::CheckWeight<Runtime>,
+	pallet_tx_pause::CheckTxPause<Runtime>,
+	pallet_account_freeze::CheckAccountFreeze<Runtime>,
 	pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
 );
 /// Unchecked extrinsic type as expected by this runtime.
@@ -666,6 +748,7 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsReversedWithSystemFirst,
+	pallet_tx_pause::migrations::ReconcilePauseEntries<Runtime>,
 >;
 
 impl_runtime_apis! {
@@ -769,6 +852,21 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_tx_pause_rpc_runtime_api::TransactionPauseApi<Block> for Runtime {
+		fn paused_transactions() -> Vec<(Vec<u8>, Vec<u8>, pallet_tx_pause_rpc_runtime_api::PauseStatus)> {
+			TransactionPause::pause_entries()
+		}
+
+		fn call_filter_status(call: Vec<u8>) -> pallet_tx_pause_rpc_runtime_api::CallFilterStatus {
+			use pallet_tx_pause_rpc_runtime_api::CallFilterStatus;
+			match <Call as codec::Decode>::decode(&mut &call[..]) {
+				Ok(call) if MantaFilter::contains(&call) => TransactionPause::throttle_status(&call).unwrap_or(CallFilterStatus::Allowed),
+				Ok(call) => TransactionPause::pause_status(&call).unwrap_or(CallFilterStatus::NotAllowed),
+				Err(_) => CallFilterStatus::InvalidCall,
+			}
+		}
+	}
+
 	impl cumulus_primitives_core::CollectCollationInfo<Block> for Runtime {
 		fn collect_collation_info(header: &<Block as BlockT>::Header) -> cumulus_primitives_core::CollationInfo {
 			ParachainSystem::collect_collation_info(header)
@@ -811,6 +909,8 @@ impl_runtime_apis! {
 			list_benchmark!(list, extra, pallet_multisig, Multisig);
 
 			list_benchmark!(list, extra, pallet_tx_pause, TransactionPause);
+			list_benchmark!(list, extra, calamari_vesting, CalamariVesting);
+			list_benchmark!(list, extra, pallet_account_freeze, AccountFreeze);
 
 			let storage_info = AllPalletsReversedWithSystemFirst::storage_info();
 
@@ -854,6 +954,8 @@ impl_runtime_apis! {
 			add_benchmark!(params, batches, pallet_utility, Utility);
 			add_benchmark!(params, batches, pallet_multisig, Multisig);
 			add_benchmark!(params, batches, pallet_tx_pause, TransactionPause);
+			add_benchmark!(params, batches, calamari_vesting, CalamariVesting);
+			add_benchmark!(params, batches, pallet_account_freeze, AccountFreeze);
 
 			if batches.is_empty() { return Err("Benchmark not found for this pallet.".into()) }
 			Ok(batches)
@@ -0,0 +1,243 @@
+// Copyright 2020-2022 Manta Network.
+// This file is part of Manta.
+
+// Manta is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Manta is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Manta.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Placeholder weights for calamari_vesting
+//!
+//! THESE ARE NOT BENCHMARK RESULTS. They were estimated by hand from the storage accesses
+//! of each extrinsic, and must be regenerated on reference hardware with the command below
+//! before they are relied on.
+
+// Command to regenerate:
+// manta
+// benchmark
+// --chain=manta-local
+// --pallet=calamari_vesting
+// --extrinsic=*
+// --execution=Wasm
+// --wasm-execution=Compiled
+// --heap-pages=4096
+// --repeat=20
+// --steps=50
+// --template=.github/resources/frame-weight-template.hbs
+// --output=calamari_vesting.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for calamari_vesting.
+pub trait WeightInfo {
+	fn update_vesting_schedule() -> Weight;
+	fn vest() -> Weight;
+	fn vested_transfer() -> Weight;
+	fn merge_grants() -> Weight;
+	fn vest_other() -> Weight;
+	fn force_vested_transfer() -> Weight;
+	fn revoke_vesting() -> Weight;
+	fn replace_vesting_schedule() -> Weight;
+	fn sweep_vesting_account() -> Weight;
+	fn batch_vested_transfer(n: u32, ) -> Weight;
+}
+
+/// Weights for calamari_vesting using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> calamari_vesting::WeightInfo for SubstrateWeight<T> {
+	// Storage: CalamariVesting VestingSchedule (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	fn update_vesting_schedule() -> Weight {
+		(15_417_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: CalamariVesting VestingGrants (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn vest() -> Weight {
+		(39_069_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: CalamariVesting VestingGrants (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Balances Locks (r:1 w:1)
+	fn vested_transfer() -> Weight {
+		(67_513_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: CalamariVesting VestingGrants (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn merge_grants() -> Weight {
+		(31_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: CalamariVesting VestingGrants (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn vest_other() -> Weight {
+		(40_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: System Account (r:2 w:2)
+	// Storage: CalamariVesting VestingGrants (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Balances Locks (r:1 w:1)
+	fn force_vested_transfer() -> Weight {
+		(70_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: CalamariVesting VestingGrants (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn revoke_vesting() -> Weight {
+		(52_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: CalamariVesting VestingSchedule (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	fn replace_vesting_schedule() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: CalamariVesting VestingGrants (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Balances Locks (r:1 w:1)
+	fn sweep_vesting_account() -> Weight {
+		(30_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: System Account (r:1 w:1)
+	// Storage: CalamariVesting VestingSchedule (r:1 w:0)
+	// Storage: CalamariVesting VestingGrants (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Balances Locks (r:1 w:1)
+	fn batch_vested_transfer(n: u32, ) -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add((61_000_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads((4 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(n as Weight)))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: CalamariVesting VestingSchedule (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	fn update_vesting_schedule() -> Weight {
+		(15_417_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: CalamariVesting VestingGrants (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn vest() -> Weight {
+		(39_069_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: CalamariVesting VestingGrants (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Balances Locks (r:1 w:1)
+	fn vested_transfer() -> Weight {
+		(67_513_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: CalamariVesting VestingGrants (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn merge_grants() -> Weight {
+		(31_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: CalamariVesting VestingGrants (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn vest_other() -> Weight {
+		(40_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: System Account (r:2 w:2)
+	// Storage: CalamariVesting VestingGrants (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Balances Locks (r:1 w:1)
+	fn force_vested_transfer() -> Weight {
+		(70_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	// Storage: CalamariVesting VestingGrants (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn revoke_vesting() -> Weight {
+		(52_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	// Storage: CalamariVesting VestingSchedule (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	fn replace_vesting_schedule() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: CalamariVesting VestingGrants (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Balances Locks (r:1 w:1)
+	fn sweep_vesting_account() -> Weight {
+		(30_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: System Account (r:1 w:1)
+	// Storage: CalamariVesting VestingSchedule (r:1 w:0)
+	// Storage: CalamariVesting VestingGrants (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Balances Locks (r:1 w:1)
+	fn batch_vested_transfer(n: u32, ) -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add((61_000_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((4 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((3 as Weight).saturating_mul(n as Weight)))
+	}
+}
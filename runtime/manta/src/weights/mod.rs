@@ -16,7 +16,9 @@
 
 //! A list of the different weight modules for our runtime.
 
+pub mod calamari_vesting;
 pub mod frame_system;
+pub mod pallet_account_freeze;
 pub mod pallet_balances;
 pub mod pallet_multisig;
 pub mod pallet_preimage;
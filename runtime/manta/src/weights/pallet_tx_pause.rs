@@ -14,13 +14,13 @@
 // You should have received a copy of the GNU General Public License
 // along with Manta.  If not, see <http://www.gnu.org/licenses/>.
 
-//! Autogenerated weights for pallet_tx_pause
+//! Placeholder weights for pallet_tx_pause
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
-//! DATE: 2022-02-06, STEPS: `50`, REPEAT: 20, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("manta-local"), DB CACHE: 1024
+//! THESE ARE NOT BENCHMARK RESULTS. They were estimated by hand from the storage accesses
+//! of each extrinsic, and must be regenerated on reference hardware with the command below
+//! before they are relied on.
 
-// Executed Command:
+// Command to regenerate:
 // manta
 // benchmark
 // --chain=manta-local
@@ -45,37 +45,230 @@ use sp_std::marker::PhantomData;
 pub trait WeightInfo {
 	fn pause_transaction() -> Weight;
 	fn unpause_transaction() -> Weight;
+	fn pause_pallet() -> Weight;
+	fn unpause_pallet() -> Weight;
+	fn on_initialize(n: u32, ) -> Weight;
+	fn pause_transactions(n: u32, ) -> Weight;
+	fn unpause_transactions(n: u32, ) -> Weight;
+	fn halt() -> Weight;
+	fn resume() -> Weight;
+	fn throttle_transaction() -> Weight;
+	fn unthrottle_transaction() -> Weight;
+	fn pause_call() -> Weight;
+	fn unpause_call() -> Weight;
+	fn set_strict_pause() -> Weight;
+	fn force_dispatch_paused() -> Weight;
 }
 
 /// Weights for pallet_tx_pause using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> pallet_tx_pause::WeightInfo for SubstrateWeight<T> {
 	// Storage: TransactionPause PausedTransactions (r:1 w:1)
+	// Storage: TransactionPause PauseExpiries (r:1 w:1)
+	// Storage: TransactionPause PauseExpiryQueue (r:1 w:1)
 	fn pause_transaction() -> Weight {
 		(12_947_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(1 as Weight))
-			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
 	}
 	// Storage: TransactionPause PausedTransactions (r:1 w:1)
+	// Storage: TransactionPause PauseExpiries (r:0 w:1)
 	fn unpause_transaction() -> Weight {
 		(14_530_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: TransactionPause PausedPallets (r:1 w:1)
+	fn pause_pallet() -> Weight {
+		(13_339_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TransactionPause PausedPallets (r:1 w:1)
+	fn unpause_pallet() -> Weight {
+		(13_985_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TransactionPause ExpiryCursor (r:1 w:0)
+	// Storage: TransactionPause PauseExpiryQueue (r:1 w:1)
+	// Storage: TransactionPause PauseExpiries (r:1 w:1)
+	// Storage: TransactionPause PausedTransactions (r:0 w:1)
+	fn on_initialize(n: u32, ) -> Weight {
+		(3_795_000 as Weight)
+			.saturating_add((20_842_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(n as Weight)))
+	}
+	// Storage: TransactionPause PausedTransactions (r:1 w:1)
+	// Storage: TransactionPause PauseExpiries (r:1 w:1)
+	fn pause_transactions(n: u32, ) -> Weight {
+		(4_082_000 as Weight)
+			.saturating_add((12_516_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(n as Weight)))
+	}
+	// Storage: TransactionPause PausedTransactions (r:1 w:1)
+	// Storage: TransactionPause PauseExpiries (r:0 w:1)
+	fn unpause_transactions(n: u32, ) -> Weight {
+		(4_473_000 as Weight)
+			.saturating_add((13_023_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(n as Weight)))
+	}
+	// Storage: TransactionPause EmergencyHalt (r:1 w:1)
+	fn halt() -> Weight {
+		(13_888_000 as Weight)
 			.saturating_add(T::DbWeight::get().reads(1 as Weight))
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	// Storage: TransactionPause EmergencyHalt (r:1 w:1)
+	fn resume() -> Weight {
+		(14_261_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TransactionPause ThrottledTransactions (r:0 w:1)
+	fn throttle_transaction() -> Weight {
+		(14_653_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TransactionPause ThrottledTransactions (r:1 w:1)
+	fn unthrottle_transaction() -> Weight {
+		(15_056_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TransactionPause PausedCallIndices (r:1 w:1)
+	// Storage: TransactionPause PausedCallIndexCount (r:1 w:1)
+	fn pause_call() -> Weight {
+		(15_825_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: TransactionPause PausedCallIndices (r:1 w:1)
+	// Storage: TransactionPause PausedCallIndexCount (r:1 w:1)
+	fn unpause_call() -> Weight {
+		(15_399_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: TransactionPause PausedTransactions (r:1 w:1)
+	fn set_strict_pause() -> Weight {
+		(16_687_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TransactionPause PausedTransactions (r:1 w:0)
+	fn force_dispatch_paused() -> Weight {
+		(13_155_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+	}
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
 	// Storage: TransactionPause PausedTransactions (r:1 w:1)
+	// Storage: TransactionPause PauseExpiries (r:1 w:1)
+	// Storage: TransactionPause PauseExpiryQueue (r:1 w:1)
 	fn pause_transaction() -> Weight {
 		(12_947_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
 	}
 	// Storage: TransactionPause PausedTransactions (r:1 w:1)
+	// Storage: TransactionPause PauseExpiries (r:0 w:1)
 	fn unpause_transaction() -> Weight {
 		(14_530_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: TransactionPause PausedPallets (r:1 w:1)
+	fn pause_pallet() -> Weight {
+		(13_339_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TransactionPause PausedPallets (r:1 w:1)
+	fn unpause_pallet() -> Weight {
+		(13_985_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TransactionPause ExpiryCursor (r:1 w:0)
+	// Storage: TransactionPause PauseExpiryQueue (r:1 w:1)
+	// Storage: TransactionPause PauseExpiries (r:1 w:1)
+	// Storage: TransactionPause PausedTransactions (r:0 w:1)
+	fn on_initialize(n: u32, ) -> Weight {
+		(3_795_000 as Weight)
+			.saturating_add((20_842_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((2 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((3 as Weight).saturating_mul(n as Weight)))
+	}
+	// Storage: TransactionPause PausedTransactions (r:1 w:1)
+	// Storage: TransactionPause PauseExpiries (r:1 w:1)
+	fn pause_transactions(n: u32, ) -> Weight {
+		(4_082_000 as Weight)
+			.saturating_add((12_516_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads((2 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(n as Weight)))
+	}
+	// Storage: TransactionPause PausedTransactions (r:1 w:1)
+	// Storage: TransactionPause PauseExpiries (r:0 w:1)
+	fn unpause_transactions(n: u32, ) -> Weight {
+		(4_473_000 as Weight)
+			.saturating_add((13_023_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(n as Weight)))
+	}
+	// Storage: TransactionPause EmergencyHalt (r:1 w:1)
+	fn halt() -> Weight {
+		(13_888_000 as Weight)
 			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
 	}
+	// Storage: TransactionPause EmergencyHalt (r:1 w:1)
+	fn resume() -> Weight {
+		(14_261_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TransactionPause ThrottledTransactions (r:0 w:1)
+	fn throttle_transaction() -> Weight {
+		(14_653_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TransactionPause ThrottledTransactions (r:1 w:1)
+	fn unthrottle_transaction() -> Weight {
+		(15_056_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TransactionPause PausedCallIndices (r:1 w:1)
+	// Storage: TransactionPause PausedCallIndexCount (r:1 w:1)
+	fn pause_call() -> Weight {
+		(15_825_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: TransactionPause PausedCallIndices (r:1 w:1)
+	// Storage: TransactionPause PausedCallIndexCount (r:1 w:1)
+	fn unpause_call() -> Weight {
+		(15_399_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: TransactionPause PausedTransactions (r:1 w:1)
+	fn set_strict_pause() -> Weight {
+		(16_687_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TransactionPause PausedTransactions (r:1 w:0)
+	fn force_dispatch_paused() -> Weight {
+		(13_155_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+	}
 }